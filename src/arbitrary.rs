@@ -0,0 +1,74 @@
+use core::mem::size_of;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::*;
+
+macro_rules! impl_arbitrary {
+    ($type:ident, $p:ty, $u:ty, $add_unsigned:ident $(,)?) => {
+        impl_arbitrary!(@impl $type, $p, $u, $add_unsigned, false);
+    };
+    (@impl $type:ident, $p:ty, $u:ty, $add_unsigned:ident, $nonzero:literal) => {
+        impl<'a, const MIN: $p, const MAX: $p> Arbitrary<'a> for $type<MIN, MAX> {
+            // comparisons only needed for signed numbers
+            #[allow(unused_comparisons)]
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let skip_zero = $nonzero && MIN < 0 && MAX > 0;
+                // pick an offset from `MIN`, so that no data results in `MIN`
+                let span = MAX.abs_diff(MIN) - <$u>::from(skip_zero);
+                let value = MIN.$add_unsigned(u.int_in_range(0..=span)?);
+                let value = if skip_zero && value >= 0 {
+                    value + 1
+                } else {
+                    value
+                };
+
+                // saturate if there's a bug and a value out of range
+                Ok(Self::with_primitive(value).unwrap_or(Self::MIN))
+            }
+
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (0, Some(size_of::<$p>()))
+            }
+        }
+    };
+}
+
+macro_rules! impl_arbitrary_nonzero {
+    ($type:ident, $p:ty, $u:ty, $add_unsigned:ident $(,)?) => {
+        impl_arbitrary!(@impl $type, $p, $u, $add_unsigned, true);
+    };
+}
+
+impl_arbitrary!(RangedU8, u8, u8, wrapping_add);
+impl_arbitrary!(RangedU16, u16, u16, wrapping_add);
+impl_arbitrary!(RangedU32, u32, u32, wrapping_add);
+impl_arbitrary!(RangedU64, u64, u64, wrapping_add);
+impl_arbitrary!(RangedU128, u128, u128, wrapping_add);
+impl_arbitrary!(RangedUsize, usize, usize, wrapping_add);
+
+impl_arbitrary!(RangedI8, i8, u8, wrapping_add_unsigned);
+impl_arbitrary!(RangedI16, i16, u16, wrapping_add_unsigned);
+impl_arbitrary!(RangedI32, i32, u32, wrapping_add_unsigned);
+impl_arbitrary!(RangedI64, i64, u64, wrapping_add_unsigned);
+impl_arbitrary!(RangedI128, i128, u128, wrapping_add_unsigned);
+impl_arbitrary!(RangedIsize, isize, usize, wrapping_add_unsigned);
+
+impl_arbitrary_nonzero!(RangedNonZeroU8, u8, u8, wrapping_add);
+impl_arbitrary_nonzero!(RangedNonZeroU16, u16, u16, wrapping_add);
+impl_arbitrary_nonzero!(RangedNonZeroU32, u32, u32, wrapping_add);
+impl_arbitrary_nonzero!(RangedNonZeroU64, u64, u64, wrapping_add);
+impl_arbitrary_nonzero!(RangedNonZeroU128, u128, u128, wrapping_add);
+impl_arbitrary_nonzero!(RangedNonZeroUsize, usize, usize, wrapping_add);
+
+impl_arbitrary_nonzero!(RangedNonZeroI8, i8, u8, wrapping_add_unsigned);
+impl_arbitrary_nonzero!(RangedNonZeroI16, i16, u16, wrapping_add_unsigned);
+impl_arbitrary_nonzero!(RangedNonZeroI32, i32, u32, wrapping_add_unsigned);
+impl_arbitrary_nonzero!(RangedNonZeroI64, i64, u64, wrapping_add_unsigned);
+impl_arbitrary_nonzero!(RangedNonZeroI128, i128, u128, wrapping_add_unsigned);
+impl_arbitrary_nonzero!(
+    RangedNonZeroIsize,
+    isize,
+    usize,
+    wrapping_add_unsigned
+);
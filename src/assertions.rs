@@ -43,19 +43,23 @@ impl_assertions!(RangedU16, u16);
 impl_assertions!(RangedU32, u32);
 impl_assertions!(RangedU64, u64);
 impl_assertions!(RangedU128, u128);
+impl_assertions!(RangedUsize, usize);
 impl_assertions!(RangedI8, i8);
 impl_assertions!(RangedI16, i16);
 impl_assertions!(RangedI32, i32);
 impl_assertions!(RangedI64, i64);
 impl_assertions!(RangedI128, i128);
+impl_assertions!(RangedIsize, isize);
 
 impl_assertions_nonzero!(RangedNonZeroU8, u8);
 impl_assertions_nonzero!(RangedNonZeroU16, u16);
 impl_assertions_nonzero!(RangedNonZeroU32, u32);
 impl_assertions_nonzero!(RangedNonZeroU64, u64);
 impl_assertions_nonzero!(RangedNonZeroU128, u128);
+impl_assertions_nonzero!(RangedNonZeroUsize, usize);
 impl_assertions_nonzero!(RangedNonZeroI8, i8);
 impl_assertions_nonzero!(RangedNonZeroI16, i16);
 impl_assertions_nonzero!(RangedNonZeroI32, i32);
 impl_assertions_nonzero!(RangedNonZeroI64, i64);
 impl_assertions_nonzero!(RangedNonZeroI128, i128);
+impl_assertions_nonzero!(RangedNonZeroIsize, isize);
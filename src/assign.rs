@@ -1,4 +1,10 @@
-use core::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+use core::{
+    num::NonZero,
+    ops::{
+        AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, DivAssign,
+        MulAssign, RemAssign, ShlAssign, ShrAssign, SubAssign,
+    },
+};
 
 use as_repr::AsRepr;
 
@@ -42,15 +48,59 @@ macro_rules! assign_impl {
             }
         }
 
-        /*
-        impl<T, const MIN: $p, const MAX: $p> RemAssign<T> for $t::<MIN, MAX>
+        impl<T, const MIN: $p, const MAX: $p> BitAndAssign<T> for $t<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            fn bitand_assign(&mut self, other: T) {
+                *self = *self & other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> BitOrAssign<T> for $t<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            fn bitor_assign(&mut self, other: T) {
+                *self = *self | other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> BitXorAssign<T> for $t<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            fn bitxor_assign(&mut self, other: T) {
+                *self = *self ^ other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> ShlAssign<T> for $t<MIN, MAX>
+        where
+            T: AsRepr<u32>,
+        {
+            fn shl_assign(&mut self, other: T) {
+                *self = *self << other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> ShrAssign<T> for $t<MIN, MAX>
+        where
+            T: AsRepr<u32>,
+        {
+            fn shr_assign(&mut self, other: T) {
+                *self = *self >> other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> RemAssign<T> for $t<MIN, MAX>
         where
             T: AsRepr<NonZero<$p>>,
         {
             fn rem_assign(&mut self, other: T) {
                 *self = *self % other;
             }
-        }*/
+        }
     };
 }
 
@@ -59,9 +109,11 @@ assign_impl!(RangedI16, i16, RangedNonZeroI16);
 assign_impl!(RangedI32, i32, RangedNonZeroI32);
 assign_impl!(RangedI64, i64, RangedNonZeroI64);
 assign_impl!(RangedI128, i128, RangedNonZeroI128);
+assign_impl!(RangedIsize, isize, RangedNonZeroIsize);
 
 assign_impl!(RangedU8, u8, RangedNonZeroU8);
 assign_impl!(RangedU16, u16, RangedNonZeroU16);
 assign_impl!(RangedU32, u32, RangedNonZeroU32);
 assign_impl!(RangedU64, u64, RangedNonZeroU64);
 assign_impl!(RangedU128, u128, RangedNonZeroU128);
+assign_impl!(RangedUsize, usize, RangedNonZeroUsize);
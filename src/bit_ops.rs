@@ -0,0 +1,1130 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use as_repr::AsRepr;
+
+use super::{
+    ops::{signed_checked, unsigned_checked},
+    *,
+};
+
+macro_rules! impl_bit_ops {
+    (
+        $type:ident,
+        $p:ty,
+        $with:ident,
+        $ret:ident,
+        $checked:ident,
+        $bounds:ident::$kind:ident,
+        $neg_err:literal,
+        $pos_err:literal $(,)?
+    ) => {
+        impl<T, const MIN: $p, const MAX: $p> BitAnd<T> for $type<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn bitand(self, other: T) -> Self {
+                self.checked_and(other).expect("out of range")
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> BitOr<T> for $type<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn bitor(self, other: T) -> Self {
+                self.checked_or(other).expect("out of range")
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> BitXor<T> for $type<MIN, MAX>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn bitxor(self, other: T) -> Self {
+                self.checked_xor(other).expect("out of range")
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> Not for $type<MIN, MAX> {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.checked_not().expect("out of range")
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Shl<T> for $type<MIN, MAX>
+        where
+            T: AsRepr<u32>,
+        {
+            type Output = Self;
+
+            fn shl(self, other: T) -> Self {
+                self.checked_shl(other).expect("out of range")
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Shr<T> for $type<MIN, MAX>
+        where
+            T: AsRepr<u32>,
+        {
+            type Output = Self;
+
+            fn shr(self, other: T) -> Self {
+                self.checked_shr(other).expect("out of range")
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Bitwise AND `self` with a number.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<4, 15>::new::<13>();")]
+            ///
+            /// assert_eq!(a.checked_and(6).unwrap().get(), 4);
+            #[doc = concat!("assert_eq!(a.checked_and(3), ", $neg_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_and(self, other: impl AsRepr<$p>) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+
+                $checked(Self::$with(self.get() & other))
+            }
+
+            /// Bitwise AND `self` with a number.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<4, 15>::new::<13>();")]
+            ///
+            /// assert_eq!(a.saturating_and(6).get(), 4);
+            /// assert_eq!(a.saturating_and(3).get(), 4);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_and(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                match Self::$with(self.get() & other) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Bitwise OR `self` with a number.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.checked_or(2).unwrap().get(), 11);
+            #[doc = concat!("assert_eq!(a.checked_or(4), ", $pos_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_or(self, other: impl AsRepr<$p>) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+
+                $checked(Self::$with(self.get() | other))
+            }
+
+            /// Bitwise OR `self` with a number.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.saturating_or(2).get(), 11);
+            /// assert_eq!(a.saturating_or(4).get(), 12);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_or(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                match Self::$with(self.get() | other) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Bitwise XOR `self` with a number.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<2, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.checked_xor(11).unwrap().get(), 2);
+            #[doc = concat!("assert_eq!(a.checked_xor(8), ", $neg_err, ");")]
+            #[doc = concat!("assert_eq!(a.checked_xor(4), ", $pos_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_xor(self, other: impl AsRepr<$p>) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+
+                $checked(Self::$with(self.get() ^ other))
+            }
+
+            /// Bitwise XOR `self` with a number.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<2, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.saturating_xor(8).get(), 2);
+            /// assert_eq!(a.saturating_xor(4).get(), 12);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_xor(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                match Self::$with(self.get() ^ other) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Invert every bit of `self`.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<{ ", stringify!($p), "::MIN }, { ", stringify!($p), "::MAX }>::MIN;")]
+            #[doc = concat!("let b = ", stringify!($type), "::<{ ", stringify!($p), "::MIN }, 15>::MIN;")]
+            ///
+            #[doc = concat!("assert_eq!(a.checked_not().unwrap().get(), ", stringify!($p), "::MAX);")]
+            #[doc = concat!("assert_eq!(b.checked_not(), ", $pos_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_not(self) -> $ret<Self> {
+                $checked(Self::$with(!self.get()))
+            }
+
+            /// Invert every bit of `self`.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<{ ", stringify!($p), "::MIN }, 15>::MIN;")]
+            ///
+            /// assert_eq!(a.saturating_not().get(), 15);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_not(self) -> Self {
+                match Self::$with(!self.get()) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Shift the bits of `self` to the left.
+            ///
+            /// Bits shifted past the width of the primitive are discarded, so
+            /// shifting by [`Self::BITS`] or more results in zero.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<3>();")]
+            ///
+            /// assert_eq!(a.checked_shl(2).unwrap().get(), 12);
+            #[doc = concat!("assert_eq!(a.checked_shl(3), ", $pos_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_shl(self, rhs: impl AsRepr<u32>) -> $ret<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                $checked(Self::$with(Self::unbounded_shl(self.get(), rhs)))
+            }
+
+            /// Shift the bits of `self` to the left.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<3>();")]
+            ///
+            /// assert_eq!(a.saturating_shl(2).get(), 12);
+            /// assert_eq!(a.saturating_shl(3).get(), 12);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_shl(self, rhs: impl AsRepr<u32>) -> Self {
+                let rhs = as_repr::as_repr(rhs);
+
+                match Self::$with(Self::unbounded_shl(self.get(), rhs)) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Shift the bits of `self` to the right.
+            ///
+            /// Shifting by [`Self::BITS`] or more results in zero, or `-1` for
+            /// negative numbers.
+            ///
+            #[doc = concat!("Returns `", $neg_err, "` or `", $pos_err, "` if the result is out of range.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<2, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.checked_shr(1).unwrap().get(), 4);
+            #[doc = concat!("assert_eq!(a.checked_shr(3), ", $neg_err, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_shr(self, rhs: impl AsRepr<u32>) -> $ret<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                $checked(Self::$with(Self::unbounded_shr(self.get(), rhs)))
+            }
+
+            /// Shift the bits of `self` to the right.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<2, 12>::new::<9>();")]
+            ///
+            /// assert_eq!(a.saturating_shr(1).get(), 4);
+            /// assert_eq!(a.saturating_shr(3).get(), 2);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_shr(self, rhs: impl AsRepr<u32>) -> Self {
+                let rhs = as_repr::as_repr(rhs);
+
+                match Self::$with(Self::unbounded_shr(self.get(), rhs)) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Bitwise AND two numbers together.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 15>::new::<13>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<0, 3>::new::<3>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 3> = a.and_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 1);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 15>::new::<13>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<0, 3>::new::<3>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<1, 3> = a.and_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn and_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = $bounds::$kind(
+                        BitOp::And,
+                        (MIN, MAX),
+                        (RHS_MIN, RHS_MAX),
+                    );
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get() & rhs.get())
+            }
+
+            /// Bitwise OR two numbers together.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<4, 4>::new::<4>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<4, 7> = a.or_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<4, 4>::new::<4>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 7> = a.or_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn or_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = $bounds::$kind(
+                        BitOp::Or,
+                        (MIN, MAX),
+                        (RHS_MIN, RHS_MAX),
+                    );
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get() | rhs.get())
+            }
+
+            /// Bitwise XOR two numbers together.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<4, 7>::new::<5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<4, 7> = a.xor_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 4);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<4, 7>::new::<5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 7> = a.xor_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 4);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn xor_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = $bounds::$kind(
+                        BitOp::Xor,
+                        (MIN, MAX),
+                        (RHS_MIN, RHS_MAX),
+                    );
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get() ^ rhs.get())
+            }
+
+            /// Shift the bits of `self` to the left by a number.
+            ///
+            /// Won't compile if any bits would be shifted out.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 3>::new::<3>();")]
+            /// let b = ranch::RangedU32::<1, 2>::new::<2>();
+            #[doc = concat!("let output: ", stringify!($type), "<2, 12> = a.shl_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 12);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 3>::new::<3>();")]
+            #[doc = concat!("let b = ranch::RangedU32::<1, { ", stringify!($p), "::BITS }>::new::<2>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<2, 0> = a.shl_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 12);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn shl_ranged<
+                const RHS_MIN: u32,
+                const RHS_MAX: u32,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: RangedU32<RHS_MIN, RHS_MAX>,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                // `MIN` and `MAX` comparisons only needed for signed numbers
+                #[allow(unused_comparisons)]
+                const {
+                    let min = Self::unbounded_shl(MIN, RHS_MAX);
+                    let max = Self::unbounded_shl(MAX, RHS_MAX);
+
+                    if Self::unbounded_shr(min, RHS_MAX) != MIN
+                        || Self::unbounded_shr(max, RHS_MAX) != MAX
+                    {
+                        panic!("Shift overflow");
+                    }
+
+                    let min = if MIN < 0 {
+                        min
+                    } else {
+                        Self::unbounded_shl(MIN, RHS_MIN)
+                    };
+                    let max = if MAX >= 0 {
+                        max
+                    } else {
+                        Self::unbounded_shl(MAX, RHS_MIN)
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(Self::unbounded_shl(self.get(), rhs.get()))
+            }
+
+            /// Shift the bits of `self` to the right by a number.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<8, 12>::new::<9>();")]
+            /// let b = ranch::RangedU32::<1, 2>::new::<1>();
+            #[doc = concat!("let output: ", stringify!($type), "<2, 6> = a.shr_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 4);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<8, 12>::new::<9>();")]
+            /// let b = ranch::RangedU32::<1, 2>::new::<1>();
+            #[doc = concat!("let output: ", stringify!($type), "<2, 4> = a.shr_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 4);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn shr_ranged<
+                const RHS_MIN: u32,
+                const RHS_MAX: u32,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: RangedU32<RHS_MIN, RHS_MAX>,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                // `MIN` and `MAX` comparisons only needed for signed numbers
+                #[allow(unused_comparisons)]
+                const {
+                    let min = if MIN < 0 {
+                        Self::unbounded_shr(MIN, RHS_MIN)
+                    } else {
+                        Self::unbounded_shr(MIN, RHS_MAX)
+                    };
+                    let max = if MAX >= 0 {
+                        Self::unbounded_shr(MAX, RHS_MIN)
+                    } else {
+                        Self::unbounded_shr(MAX, RHS_MAX)
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(Self::unbounded_shr(self.get(), rhs.get()))
+            }
+
+            /// Bitwise AND `self` with a number.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 15>::new::<13>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            /// assert_eq!(a.and::<0b11, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn and<
+                const RHS: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { $type::<RHS, RHS>::new::<RHS>() };
+
+                self.and_ranged(rhs)
+            }
+
+            /// Bitwise OR `self` with a number.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<8, 11>::new::<9>();")]
+            /// assert_eq!(a.or::<0b1000, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn or<
+                const RHS: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { $type::<RHS, RHS>::new::<RHS>() };
+
+                self.or_ranged(rhs)
+            }
+
+            /// Bitwise XOR `self` with a number.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 3>::new::<1>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<0, 3>::new::<2>();")]
+            /// assert_eq!(a.xor::<0b11, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn xor<
+                const RHS: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { $type::<RHS, RHS>::new::<RHS>() };
+
+                self.xor_ranged(rhs)
+            }
+
+            /// Invert every bit of `self`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 15>::new::<5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<{ !15 }, { !0 }> = a.not();")]
+            ///
+            /// assert_eq!(output.get(), !5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 15>::new::<5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<{ !15 }, { !1 }> = a.not();")]
+            ///
+            /// assert_eq!(output.get(), !5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn not<const OUTPUT_MIN: $p, const OUTPUT_MAX: $p>(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    if !MAX != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if !MIN != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(!self.get())
+            }
+
+            /// Shift the bits of `self` to the left by a constant.
+            ///
+            /// Won't compile if any bits would be shifted out.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 15>::new::<3>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<4, 60>::new::<12>();")]
+            /// assert_eq!(a.shl::<2, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn shl<
+                const RHS: u32,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { RangedU32::<RHS, RHS>::new::<RHS>() };
+
+                self.shl_ranged(rhs)
+            }
+
+            /// Shift the bits of `self` to the right by a constant.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<4, 15>::new::<13>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<1, 3>::new::<3>();")]
+            /// assert_eq!(a.shr::<2, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn shr<
+                const RHS: u32,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { RangedU32::<RHS, RHS>::new::<RHS>() };
+
+                self.shr_ranged(rhs)
+            }
+
+            const fn unbounded_shl(value: $p, rhs: u32) -> $p {
+                if rhs < <$p>::BITS { value << rhs } else { 0 }
+            }
+
+            const fn unbounded_shr(value: $p, rhs: u32) -> $p {
+                if rhs < <$p>::BITS {
+                    value >> rhs
+                } else {
+                    // sign extension for signed numbers
+                    value >> (<$p>::BITS - 1) >> 1
+                }
+            }
+        }
+    };
+}
+
+macro_rules! bit_bounds {
+    ($name:ident, $u:ty, $s:ty $(,)?) => {
+        mod $name {
+            use super::BitOp;
+
+            /// Calculate the exact output range of a bitwise operation on two
+            /// unsigned ranges.
+            pub(super) const fn unsigned(
+                op: BitOp,
+                (a_min, a_max): ($u, $u),
+                (b_min, b_max): ($u, $u),
+            ) -> ($u, $u) {
+                match op {
+                    BitOp::And => (
+                        min_and(a_min, a_max, b_min, b_max),
+                        max_and(a_min, a_max, b_min, b_max),
+                    ),
+                    BitOp::Or => (
+                        min_or(a_min, a_max, b_min, b_max),
+                        max_or(a_min, a_max, b_min, b_max),
+                    ),
+                    BitOp::Xor => (
+                        min_and(a_min, a_max, !b_max, !b_min)
+                            | min_and(!a_max, !a_min, b_min, b_max),
+                        max_or(
+                            0,
+                            max_and(a_min, a_max, !b_max, !b_min),
+                            0,
+                            max_and(!a_max, !a_min, b_min, b_max),
+                        ),
+                    ),
+                }
+            }
+
+            /// Calculate the exact output range of a bitwise operation on two
+            /// signed ranges.
+            ///
+            /// Each range is split into its negative and non-negative parts,
+            /// which are ordered the same whether their bits are interpreted
+            /// as signed or unsigned.  The sign of the output is known for each
+            /// combination of parts.
+            pub(super) const fn signed(
+                op: BitOp,
+                a: ($s, $s),
+                b: ($s, $s),
+            ) -> ($s, $s) {
+                let (a, b) = (split(a), split(b));
+                let (mut min, mut max) = (<$s>::MAX, <$s>::MIN);
+                let mut i = 0;
+
+                while i < a.len() {
+                    let mut j = 0;
+
+                    while j < b.len() {
+                        if let (Some(a), Some(b)) = (a[i], b[j]) {
+                            let (part_min, part_max) = unsigned(
+                                op,
+                                (a.0 as $u, a.1 as $u),
+                                (b.0 as $u, b.1 as $u),
+                            );
+                            let (part_min, part_max) =
+                                (part_min as $s, part_max as $s);
+
+                            if part_min < min {
+                                min = part_min;
+                            }
+
+                            if part_max > max {
+                                max = part_max;
+                            }
+                        }
+
+                        j += 1;
+                    }
+
+                    i += 1;
+                }
+
+                (min, max)
+            }
+
+            const fn split((min, max): ($s, $s)) -> [Option<($s, $s)>; 2] {
+                let negative = if min < 0 {
+                    Some((min, if max < 0 { max } else { -1 }))
+                } else {
+                    None
+                };
+                let non_negative = if max >= 0 {
+                    Some((if min >= 0 { min } else { 0 }, max))
+                } else {
+                    None
+                };
+
+                [negative, non_negative]
+            }
+
+            // The following are from Hacker's Delight, section 4-3
+
+            const fn min_or(mut a: $u, b: $u, mut c: $u, d: $u) -> $u {
+                let mut m = 1 << (<$u>::BITS - 1);
+
+                while m != 0 {
+                    if !a & c & m != 0 {
+                        let temp = (a | m) & m.wrapping_neg();
+
+                        if temp <= b {
+                            a = temp;
+                            break;
+                        }
+                    } else if a & !c & m != 0 {
+                        let temp = (c | m) & m.wrapping_neg();
+
+                        if temp <= d {
+                            c = temp;
+                            break;
+                        }
+                    }
+
+                    m >>= 1;
+                }
+
+                a | c
+            }
+
+            const fn max_or(a: $u, mut b: $u, c: $u, mut d: $u) -> $u {
+                let mut m = 1 << (<$u>::BITS - 1);
+
+                while m != 0 {
+                    if b & d & m != 0 {
+                        let temp = (b - m) | (m - 1);
+
+                        if temp >= a {
+                            b = temp;
+                            break;
+                        }
+
+                        let temp = (d - m) | (m - 1);
+
+                        if temp >= c {
+                            d = temp;
+                            break;
+                        }
+                    }
+
+                    m >>= 1;
+                }
+
+                b | d
+            }
+
+            const fn min_and(mut a: $u, b: $u, mut c: $u, d: $u) -> $u {
+                let mut m = 1 << (<$u>::BITS - 1);
+
+                while m != 0 {
+                    if !a & !c & m != 0 {
+                        let temp = (a | m) & m.wrapping_neg();
+
+                        if temp <= b {
+                            a = temp;
+                            break;
+                        }
+
+                        let temp = (c | m) & m.wrapping_neg();
+
+                        if temp <= d {
+                            c = temp;
+                            break;
+                        }
+                    }
+
+                    m >>= 1;
+                }
+
+                a & c
+            }
+
+            const fn max_and(a: $u, mut b: $u, c: $u, mut d: $u) -> $u {
+                let mut m = 1 << (<$u>::BITS - 1);
+
+                while m != 0 {
+                    if b & !d & m != 0 {
+                        let temp = (b & !m) | (m - 1);
+
+                        if temp >= a {
+                            b = temp;
+                            break;
+                        }
+                    } else if !b & d & m != 0 {
+                        let temp = (d & !m) | (m - 1);
+
+                        if temp >= c {
+                            d = temp;
+                            break;
+                        }
+                    }
+
+                    m >>= 1;
+                }
+
+                b & d
+            }
+        }
+    };
+}
+
+impl_bit_ops!(
+    RangedI8,
+    i8,
+    with_i8,
+    Result,
+    signed_checked,
+    bits8::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+impl_bit_ops!(
+    RangedI16,
+    i16,
+    with_i16,
+    Result,
+    signed_checked,
+    bits16::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+impl_bit_ops!(
+    RangedI32,
+    i32,
+    with_i32,
+    Result,
+    signed_checked,
+    bits32::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+impl_bit_ops!(
+    RangedI64,
+    i64,
+    with_i64,
+    Result,
+    signed_checked,
+    bits64::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+impl_bit_ops!(
+    RangedI128,
+    i128,
+    with_i128,
+    Result,
+    signed_checked,
+    bits128::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+impl_bit_ops!(
+    RangedIsize,
+    isize,
+    with_isize,
+    Result,
+    signed_checked,
+    bitsize::signed,
+    "Err(Error::NegOverflow)",
+    "Err(Error::PosOverflow)",
+);
+
+impl_bit_ops!(
+    RangedU8,
+    u8,
+    with_u8,
+    Option,
+    unsigned_checked,
+    bits8::unsigned,
+    "None",
+    "None",
+);
+impl_bit_ops!(
+    RangedU16,
+    u16,
+    with_u16,
+    Option,
+    unsigned_checked,
+    bits16::unsigned,
+    "None",
+    "None",
+);
+impl_bit_ops!(
+    RangedU32,
+    u32,
+    with_u32,
+    Option,
+    unsigned_checked,
+    bits32::unsigned,
+    "None",
+    "None",
+);
+impl_bit_ops!(
+    RangedU64,
+    u64,
+    with_u64,
+    Option,
+    unsigned_checked,
+    bits64::unsigned,
+    "None",
+    "None",
+);
+impl_bit_ops!(
+    RangedU128,
+    u128,
+    with_u128,
+    Option,
+    unsigned_checked,
+    bits128::unsigned,
+    "None",
+    "None",
+);
+impl_bit_ops!(
+    RangedUsize,
+    usize,
+    with_usize,
+    Option,
+    unsigned_checked,
+    bitsize::unsigned,
+    "None",
+    "None",
+);
+
+bit_bounds!(bits8, u8, i8);
+bit_bounds!(bits16, u16, i16);
+bit_bounds!(bits32, u32, i32);
+bit_bounds!(bits64, u64, i64);
+bit_bounds!(bits128, u128, i128);
+bit_bounds!(bitsize, usize, isize);
+
+#[derive(Copy, Clone, Debug)]
+enum BitOp {
+    And,
+    Or,
+    Xor,
+}
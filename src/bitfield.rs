@@ -0,0 +1,236 @@
+use super::{pack::Pack, *};
+
+/// Declare a struct of ranged fields packed into a single unsigned integer.
+///
+/// Each field is declared with a getter name, a setter name, and a type that
+/// implements [`Pack`](crate::pack::Pack).  The first field occupies the least
+/// significant bits, and each following field is packed directly above the
+/// previous one, taking up exactly [`Pack::WIDTH`](crate::pack::Pack::WIDTH)
+/// bits.  Fields must fit in the backing integer, which is checked at compile
+/// time.
+///
+/// The generated struct derives [`Copy`], [`Clone`], [`Eq`], [`PartialEq`],
+/// and [`Hash`], and implements [`Debug`](core::fmt::Debug) by listing its
+/// fields.  It provides:
+///
+///  - `WIDTH`: the total number of bits used by the fields
+///  - `new()`: create from every field, in order
+///  - `from_bits()`: validate and create from the backing integer
+///  - `to_bits()`: return the backing integer
+///  - a getter returning the exact ranged type of each field
+///  - a setter taking the exact ranged type of each field
+///
+/// ```rust
+/// use ranch::{
+///     bitwise::{NonZeroU4, U3, U5},
+///     range::Error,
+///     RangedU8,
+/// };
+///
+/// ranch::bitfield! {
+///     /// Packet header
+///     pub struct Header(u16) {
+///         /// Protocol version
+///         pub version, set_version: U3,
+///         /// Packet kind
+///         pub kind, set_kind: RangedU8<0, 20>,
+///         /// Hop limit
+///         pub hops, set_hops: NonZeroU4,
+///     }
+/// }
+///
+/// let mut header = Header::new(
+///     U3::new::<5>(),
+///     RangedU8::new::<17>(),
+///     NonZeroU4::new::<1>(),
+/// );
+///
+/// assert_eq!(Header::WIDTH, 12);
+/// assert_eq!(header.to_bits(), 0b0001_10001_101);
+/// assert_eq!(header.kind(), RangedU8::<0, 20>::new::<17>());
+///
+/// header.set_hops(NonZeroU4::new::<15>());
+/// assert_eq!(header.to_bits(), 0b1111_10001_101);
+/// assert_eq!(Header::from_bits(0b1111_10001_101), Ok(header));
+///
+/// // `kind` is 31, which is out of range
+/// assert_eq!(Header::from_bits(0b1111_11111_101), Err(Error::PosOverflow));
+/// // `hops` is zero
+/// assert_eq!(Header::from_bits(0b0000_10001_101), Err(Error::Zero));
+/// // bits above `WIDTH` must be zero
+/// assert_eq!(
+///     Header::from_bits(0b1_1111_10001_101),
+///     Err(Error::PosOverflow),
+/// );
+/// ```
+///
+/// Fields must fit in the backing integer:
+///
+/// ```rust,compile_fail
+/// # use ranch::bitwise::U5;
+/// ranch::bitfield! {
+///     struct TooWide(u8) {
+///         first, set_first: U5,
+///         second, set_second: U5,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($backing:ty) {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $get:ident, $set:ident: $type:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+        #[repr(transparent)]
+        $vis struct $name($backing);
+
+        const _: () = assert!(
+            $name::WIDTH <= <$backing as $crate::__private::Backing>::BITS,
+            "bitfield is wider than its backing integer",
+        );
+
+        impl $name {
+            /// The total number of bits used by the fields.
+            $vis const WIDTH: u32 =
+                0 $(+ <$type as $crate::pack::Pack>::WIDTH)*;
+
+            /// Create a new bitfield from the value of each field.
+            $vis fn new($($get: $type),*) -> Self {
+                let mut bitfield = Self(0);
+
+                $(bitfield.$set($get);)*
+
+                bitfield
+            }
+
+            /// Try to create a bitfield from its backing integer, validating
+            /// that each field is in range and that unused bits are zero.
+            $vis fn from_bits(
+                bits: $backing,
+            ) -> $crate::range::Result<Self> {
+                let bits = $crate::__private::Backing::check_width(
+                    bits,
+                    Self::WIDTH,
+                )?;
+                let offset = 0;
+
+                $(
+                    $crate::__private::Backing::get_field::<$type>(
+                        bits,
+                        offset,
+                    )?;
+
+                    let offset =
+                        offset + <$type as $crate::pack::Pack>::WIDTH;
+                )*
+
+                let _ = offset;
+
+                Ok(Self(bits))
+            }
+
+            /// Return the backing integer.
+            $vis const fn to_bits(self) -> $backing {
+                self.0
+            }
+
+            $crate::bitfield!(
+                @accessors $backing, 0;
+                $($(#[$field_attr])* $field_vis $get, $set: $type,)*
+            );
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($get), &self.$get()))*
+                    .finish()
+            }
+        }
+    };
+    (@accessors $backing:ty, $offset:expr;) => {};
+    (
+        @accessors $backing:ty, $offset:expr;
+        $(#[$field_attr:meta])*
+        $field_vis:vis $get:ident, $set:ident: $type:ty,
+        $($rest:tt)*
+    ) => {
+        $(#[$field_attr])*
+        $field_vis fn $get(self) -> $type {
+            $crate::__private::Backing::get_field(self.0, $offset)
+                .expect("out of range")
+        }
+
+        #[doc = concat!("Set [`Self::", stringify!($get), "()`].")]
+        $field_vis fn $set(&mut self, value: $type) {
+            self.0 =
+                $crate::__private::Backing::set_field(self.0, $offset, value);
+        }
+
+        $crate::bitfield!(
+            @accessors $backing,
+            $offset + <$type as $crate::pack::Pack>::WIDTH;
+            $($rest)*
+        );
+    };
+}
+
+/// An unsigned integer that can back a [`bitfield!`]
+pub trait Backing: Copy {
+    /// The size of this integer type in bits.
+    const BITS: u32;
+
+    /// Check that all bits at or above `width` are zero.
+    fn check_width(self, width: u32) -> range::Result<Self>;
+
+    /// Get the field at `offset`.
+    fn get_field<T: Pack>(self, offset: u32) -> range::Result<T>;
+
+    /// Replace the field at `offset`.
+    fn set_field<T: Pack>(self, offset: u32, value: T) -> Self;
+}
+
+macro_rules! impl_backing {
+    ($p:ty $(,)?) => {
+        impl Backing for $p {
+            const BITS: u32 = <$p>::BITS;
+
+            fn check_width(self, width: u32) -> range::Result<Self> {
+                match self.checked_shr(width).unwrap_or(0) {
+                    0 => Ok(self),
+                    _ => Err(range::Error::PosOverflow),
+                }
+            }
+
+            fn get_field<T: Pack>(self, offset: u32) -> range::Result<T> {
+                T::from_bits(u128::from(self).checked_shr(offset).unwrap_or(0))
+            }
+
+            // cast is only trivial for `u128`
+            #[allow(trivial_numeric_casts)]
+            fn set_field<T: Pack>(self, offset: u32, value: T) -> Self {
+                let mask =
+                    pack::mask(T::WIDTH).checked_shl(offset).unwrap_or(0);
+                let bits = value.to_bits().checked_shl(offset).unwrap_or(0);
+
+                ((u128::from(self) & !mask) | bits) as $p
+            }
+        }
+    };
+}
+
+impl_backing!(u8);
+impl_backing!(u16);
+impl_backing!(u32);
+impl_backing!(u64);
+impl_backing!(u128);
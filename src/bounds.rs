@@ -0,0 +1,486 @@
+//! Interval arithmetic for ranged integer operations
+//!
+//! Each primitive has a module of `const fn`s, calculating the minimum or
+//! maximum output of an operation from the inclusive bounds of its operands.
+//! These are the output bounds the `*_ranged` operations expect, and can be
+//! used in type position for your own aliases:
+//!
+//! ```rust
+//! # use ranch::{bounds::i32 as bounds, RangedI32};
+//! type Price = RangedI32<1, 100>;
+//! type Quantity = RangedI32<0, 12>;
+//! type Total = RangedI32<
+//!     { bounds::mul_min(1, 100, 0, 12) },
+//!     { bounds::mul_max(1, 100, 0, 12) },
+//! >;
+//!
+//! let total: Total = Price::new::<30>().mul_ranged(Quantity::new::<3>());
+//!
+//! assert_eq!(Total::MAX.get(), 1200);
+//! assert_eq!(total.get(), 90);
+//! ```
+//!
+//! Every function panics (failing to compile in const contexts) if an operand
+//! range is empty, or if the output range doesn't fit in the primitive.
+//!
+//! ```rust,compile_fail
+//! # use ranch::bounds;
+//! const MAX: u8 = bounds::u8::mul_max(0, 16, 0, 16);
+//! ```
+
+macro_rules! bounds {
+    ($p:ident $(,)?) => {
+        #[doc = concat!("Bounds of ranged [`", stringify!($p), "`] operations")]
+        ///
+        #[doc = concat!("[`", stringify!($p), "`]: prim@", stringify!($p))]
+        pub mod $p {
+            /// Minimum of `a + b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn add_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                add(a_min, a_max, b_min, b_max).0
+            }
+
+            /// Maximum of `a + b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn add_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                add(a_min, a_max, b_min, b_max).1
+            }
+
+            /// Minimum of `a - b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn sub_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                sub(a_min, a_max, b_min, b_max).0
+            }
+
+            /// Maximum of `a - b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn sub_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                sub(a_min, a_max, b_min, b_max).1
+            }
+
+            /// Minimum of `a * b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn mul_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                mul(a_min, a_max, b_min, b_max).0
+            }
+
+            /// Maximum of `a * b`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn mul_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                mul(a_min, a_max, b_min, b_max).1
+            }
+
+            /// Minimum of `a / b`, for `a` in `a_min..=a_max` and non-zero `b`
+            /// in `b_min..=b_max`.
+            ///
+            /// Also panics if zero is the only divisor in range.
+            #[must_use]
+            pub const fn div_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                div(a_min, a_max, b_min, b_max).0
+            }
+
+            /// Maximum of `a / b`, for `a` in `a_min..=a_max` and non-zero `b`
+            /// in `b_min..=b_max`.
+            ///
+            /// Also panics if zero is the only divisor in range.
+            #[must_use]
+            pub const fn div_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                div(a_min, a_max, b_min, b_max).1
+            }
+
+            /// Minimum of `a.pow(exp)`, for `a` in `a_min..=a_max` and `exp` in
+            /// `exp_min..=exp_max`.
+            #[must_use]
+            pub const fn pow_min(
+                a_min: $p,
+                a_max: $p,
+                exp_min: u32,
+                exp_max: u32,
+            ) -> $p {
+                pow(a_min, a_max, exp_min, exp_max).0
+            }
+
+            /// Maximum of `a.pow(exp)`, for `a` in `a_min..=a_max` and `exp` in
+            /// `exp_min..=exp_max`.
+            #[must_use]
+            pub const fn pow_max(
+                a_min: $p,
+                a_max: $p,
+                exp_min: u32,
+                exp_max: u32,
+            ) -> $p {
+                pow(a_min, a_max, exp_min, exp_max).1
+            }
+
+            /// Minimum of `a.min(b)`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn min_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                if a_min < b_min { a_min } else { b_min }
+            }
+
+            /// Maximum of `a.min(b)`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn min_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                if a_max < b_max { a_max } else { b_max }
+            }
+
+            /// Minimum of `a.max(b)`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn max_min(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                if a_min > b_min { a_min } else { b_min }
+            }
+
+            /// Maximum of `a.max(b)`, for `a` in `a_min..=a_max` and `b` in
+            /// `b_min..=b_max`.
+            #[must_use]
+            pub const fn max_max(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> $p {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                if a_max > b_max { a_max } else { b_max }
+            }
+
+            /// Minimum of `a.clamp(min, max)`, for `a` in `a_min..=a_max`,
+            /// `min` in `min_min..=min_max` and `max` in `max_min..=max_max`,
+            /// where `min <= max`.
+            ///
+            /// Also panics if `min` is always greater than `max`.
+            #[must_use]
+            pub const fn clamp_min(
+                a_min: $p,
+                a_max: $p,
+                min_min: $p,
+                min_max: $p,
+                max_min: $p,
+                max_max: $p,
+            ) -> $p {
+                check_clamp(a_min, a_max, min_min, min_max, max_min, max_max);
+
+                let max = if max_min > min_min { max_min } else { min_min };
+
+                clamp(a_min, min_min, max)
+            }
+
+            /// Maximum of `a.clamp(min, max)`, for `a` in `a_min..=a_max`,
+            /// `min` in `min_min..=min_max` and `max` in `max_min..=max_max`,
+            /// where `min <= max`.
+            ///
+            /// Also panics if `min` is always greater than `max`.
+            #[must_use]
+            pub const fn clamp_max(
+                a_min: $p,
+                a_max: $p,
+                min_min: $p,
+                min_max: $p,
+                max_min: $p,
+                max_max: $p,
+            ) -> $p {
+                check_clamp(a_min, a_max, min_min, min_max, max_min, max_max);
+
+                let min = if min_max < max_max { min_max } else { max_max };
+
+                clamp(a_max, min, max_max)
+            }
+
+            const fn check(min: $p, max: $p) {
+                if min > max {
+                    panic!("empty operand range");
+                }
+            }
+
+            const fn fit(value: Option<$p>) -> $p {
+                match value {
+                    Some(value) => value,
+                    None => panic!(concat!(
+                        "output range doesn't fit in `",
+                        stringify!($p),
+                        "`",
+                    )),
+                }
+            }
+
+            /// Minimum and maximum of the first `count` candidates, which all
+            /// must fit
+            const fn extremes(
+                candidates: &[Option<$p>],
+                count: usize,
+            ) -> ($p, $p) {
+                let (mut min, mut max) = (<$p>::MAX, <$p>::MIN);
+                let mut i = 0;
+
+                while i < count {
+                    let value = fit(candidates[i]);
+
+                    if value < min {
+                        min = value;
+                    }
+
+                    if value > max {
+                        max = value;
+                    }
+
+                    i += 1;
+                }
+
+                (min, max)
+            }
+
+            const fn add(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> ($p, $p) {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                (fit(a_min.checked_add(b_min)), fit(a_max.checked_add(b_max)))
+            }
+
+            const fn sub(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> ($p, $p) {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                (fit(a_min.checked_sub(b_max)), fit(a_max.checked_sub(b_min)))
+            }
+
+            const fn check_clamp(
+                a_min: $p,
+                a_max: $p,
+                min_min: $p,
+                min_max: $p,
+                max_min: $p,
+                max_max: $p,
+            ) {
+                check(a_min, a_max);
+                check(min_min, min_max);
+                check(max_min, max_max);
+
+                if min_min > max_max {
+                    panic!("clamp minimum is always greater than maximum");
+                }
+            }
+
+            // clamping is monotonic in every operand, so the extremes are at
+            // the lowest and highest valid operands
+            const fn clamp(value: $p, min: $p, max: $p) -> $p {
+                if value < min {
+                    min
+                } else if value > max {
+                    max
+                } else {
+                    value
+                }
+            }
+
+            // products are monotonic in each operand, so the extremes are at
+            // the corners
+            const fn mul(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> ($p, $p) {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                let candidates = [
+                    a_min.checked_mul(b_min),
+                    a_min.checked_mul(b_max),
+                    a_max.checked_mul(b_min),
+                    a_max.checked_mul(b_max),
+                ];
+
+                extremes(&candidates, candidates.len())
+            }
+
+            // quotients are monotonic in each operand for divisors of one
+            // sign, so the extremes are at the corners of the negative and
+            // positive divisor ranges, which end at -1 and 1 when spanning zero
+            const fn div(
+                a_min: $p,
+                a_max: $p,
+                b_min: $p,
+                b_max: $p,
+            ) -> ($p, $p) {
+                check(a_min, a_max);
+                check(b_min, b_max);
+
+                // -1 wraps to the maximum for unsigned numbers, which is only
+                // in range when it's also `b_max`
+                let divisors = [b_min, b_max, 1, <$p>::wrapping_sub(0, 1)];
+                let mut candidates = [None; 8];
+                let mut count = 0;
+                let mut i = 0;
+
+                while i < divisors.len() {
+                    let divisor = divisors[i];
+
+                    if divisor != 0 && b_min <= divisor && divisor <= b_max {
+                        candidates[count] = a_min.checked_div(divisor);
+                        candidates[count + 1] = a_max.checked_div(divisor);
+                        count += 2;
+                    }
+
+                    i += 1;
+                }
+
+                if count == 0 {
+                    panic!("divisor range only contains zero");
+                }
+
+                extremes(&candidates, count)
+            }
+
+            // powers are monotonic in the base away from -1..=1, and in the
+            // exponent for each parity, so the extremes are at the bounds
+            // and -1..=1 raised to the lowest and highest exponent of each
+            // parity
+            const fn pow(
+                a_min: $p,
+                a_max: $p,
+                exp_min: u32,
+                exp_max: u32,
+            ) -> ($p, $p) {
+                check(a_min, a_max);
+
+                if exp_min > exp_max {
+                    panic!("empty operand range");
+                }
+
+                let bases = [a_min, a_max, 0, 1, <$p>::wrapping_sub(0, 1)];
+                let exps = [
+                    exp_min,
+                    exp_max,
+                    if exp_min < exp_max {
+                        exp_min + 1
+                    } else {
+                        exp_min
+                    },
+                    if exp_min < exp_max {
+                        exp_max - 1
+                    } else {
+                        exp_max
+                    },
+                ];
+                let mut candidates = [None; 20];
+                let mut count = 0;
+                let mut i = 0;
+
+                while i < bases.len() {
+                    let base = bases[i];
+                    let mut j = 0;
+
+                    if a_min <= base && base <= a_max {
+                        while j < exps.len() {
+                            candidates[count] = base.checked_pow(exps[j]);
+                            count += 1;
+                            j += 1;
+                        }
+                    }
+
+                    i += 1;
+                }
+
+                extremes(&candidates, count)
+            }
+        }
+    };
+}
+
+bounds!(u8);
+bounds!(u16);
+bounds!(u32);
+bounds!(u64);
+bounds!(u128);
+bounds!(usize);
+
+bounds!(i8);
+bounds!(i16);
+bounds!(i32);
+bounds!(i64);
+bounds!(i128);
+bounds!(isize);
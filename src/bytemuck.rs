@@ -0,0 +1,64 @@
+#![allow(unsafe_code)]
+
+use ::bytemuck::{CheckedBitPattern, NoUninit, Zeroable};
+
+use super::*;
+
+macro_rules! impl_bytemuck {
+    ($type:ident, $p:ident $(,)?) => {
+        // unsafe: `repr(transparent)` on ranged types is `repr(primitive)`
+        unsafe impl<const MIN: $p, const MAX: $p> NoUninit for $type<MIN, MAX> {}
+
+        // unsafe: `repr(transparent)` on ranged types is `repr(primitive)`,
+        // and valid bit patterns are limited to valid values
+        unsafe impl<const MIN: $p, const MAX: $p> CheckedBitPattern
+            for $type<MIN, MAX>
+        {
+            type Bits = $p;
+
+            fn is_valid_bit_pattern(bits: &$p) -> bool {
+                Self::with_primitive(*bits).is_ok()
+            }
+        }
+    };
+}
+
+// Other signed ranges that contain zero (`MIN < 0 <= MAX`) can't implement
+// `Zeroable`, since a bound on `MIN` can't be expressed without unstable
+// generic const expressions, so only ranges with a minimum of zero do.
+macro_rules! impl_bytemuck_zeroable {
+    ($type:ident, $p:ident $(,)?) => {
+        impl_bytemuck!($type, $p);
+
+        // unsafe: zero is always in range when the minimum is zero
+        unsafe impl<const MAX: $p> Zeroable for $type<0, MAX> {}
+    };
+}
+
+impl_bytemuck_zeroable!(RangedU8, u8);
+impl_bytemuck_zeroable!(RangedU16, u16);
+impl_bytemuck_zeroable!(RangedU32, u32);
+impl_bytemuck_zeroable!(RangedU64, u64);
+impl_bytemuck_zeroable!(RangedU128, u128);
+impl_bytemuck_zeroable!(RangedUsize, usize);
+
+impl_bytemuck_zeroable!(RangedI8, i8);
+impl_bytemuck_zeroable!(RangedI16, i16);
+impl_bytemuck_zeroable!(RangedI32, i32);
+impl_bytemuck_zeroable!(RangedI64, i64);
+impl_bytemuck_zeroable!(RangedI128, i128);
+impl_bytemuck_zeroable!(RangedIsize, isize);
+
+impl_bytemuck!(RangedNonZeroU8, u8);
+impl_bytemuck!(RangedNonZeroU16, u16);
+impl_bytemuck!(RangedNonZeroU32, u32);
+impl_bytemuck!(RangedNonZeroU64, u64);
+impl_bytemuck!(RangedNonZeroU128, u128);
+impl_bytemuck!(RangedNonZeroUsize, usize);
+
+impl_bytemuck!(RangedNonZeroI8, i8);
+impl_bytemuck!(RangedNonZeroI16, i16);
+impl_bytemuck!(RangedNonZeroI32, i32);
+impl_bytemuck!(RangedNonZeroI64, i64);
+impl_bytemuck!(RangedNonZeroI128, i128);
+impl_bytemuck!(RangedNonZeroIsize, isize);
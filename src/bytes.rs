@@ -0,0 +1,170 @@
+use super::{ops::signed_checked, *};
+
+macro_rules! impl_bytes {
+    (
+        $type:ident,
+        $p:ty,
+        $with:ident,
+        $ret:ty,
+        $convert:path,
+        $error:literal $(,)?
+    ) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Return the memory representation of this integer as a byte
+            /// array in little-endian byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 100>::new::<42>();")]
+            ///
+            #[doc = concat!("assert_eq!(a.to_le_bytes(), 42", stringify!($p), ".to_le_bytes());")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn to_le_bytes(self) -> [u8; size_of::<$p>()] {
+                self.get().to_le_bytes()
+            }
+
+            /// Return the memory representation of this integer as a byte
+            /// array in big-endian (network) byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 100>::new::<42>();")]
+            ///
+            #[doc = concat!("assert_eq!(a.to_be_bytes(), 42", stringify!($p), ".to_be_bytes());")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn to_be_bytes(self) -> [u8; size_of::<$p>()] {
+                self.get().to_be_bytes()
+            }
+
+            /// Return the memory representation of this integer as a byte
+            /// array in native byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 100>::new::<42>();")]
+            ///
+            #[doc = concat!("assert_eq!(a.to_ne_bytes(), 42", stringify!($p), ".to_ne_bytes());")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn to_ne_bytes(self) -> [u8; size_of::<$p>()] {
+                self.get().to_ne_bytes()
+            }
+
+            /// Try to create a ranged integer from its representation as a
+            /// byte array in little-endian byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", $error, ", ", stringify!($type), "};")]
+            #[doc = concat!("type Value = ", stringify!($type), "<1, 100>;")]
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_le_bytes(42", stringify!($p), ".to_le_bytes()),")]
+            ///     Ok(Value::new::<42>()),
+            /// );
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_le_bytes(101", stringify!($p), ".to_le_bytes()),")]
+            ///     Err(Error::PosOverflow),
+            /// );
+            /// ```
+            pub const fn try_from_le_bytes(
+                bytes: [u8; size_of::<$p>()],
+            ) -> $ret {
+                $convert(Self::$with(<$p>::from_le_bytes(bytes)))
+            }
+
+            /// Try to create a ranged integer from its representation as a
+            /// byte array in big-endian (network) byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", $error, ", ", stringify!($type), "};")]
+            #[doc = concat!("type Value = ", stringify!($type), "<1, 100>;")]
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_be_bytes(42", stringify!($p), ".to_be_bytes()),")]
+            ///     Ok(Value::new::<42>()),
+            /// );
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_be_bytes(101", stringify!($p), ".to_be_bytes()),")]
+            ///     Err(Error::PosOverflow),
+            /// );
+            /// ```
+            pub const fn try_from_be_bytes(
+                bytes: [u8; size_of::<$p>()],
+            ) -> $ret {
+                $convert(Self::$with(<$p>::from_be_bytes(bytes)))
+            }
+
+            /// Try to create a ranged integer from its representation as a
+            /// byte array in native byte order.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", $error, ", ", stringify!($type), "};")]
+            #[doc = concat!("type Value = ", stringify!($type), "<1, 100>;")]
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_ne_bytes(42", stringify!($p), ".to_ne_bytes()),")]
+            ///     Ok(Value::new::<42>()),
+            /// );
+            /// assert_eq!(
+            #[doc = concat!("    Value::try_from_ne_bytes(101", stringify!($p), ".to_ne_bytes()),")]
+            ///     Err(Error::PosOverflow),
+            /// );
+            /// ```
+            pub const fn try_from_ne_bytes(
+                bytes: [u8; size_of::<$p>()],
+            ) -> $ret {
+                $convert(Self::$with(<$p>::from_ne_bytes(bytes)))
+            }
+        }
+    };
+}
+
+macro_rules! impl_bytes_ranged {
+    ($type:ident, $p:ty, $with:ident $(,)?) => {
+        impl_bytes!($type, $p, $with, Result<Self>, signed_checked, "Error");
+    };
+}
+
+macro_rules! impl_bytes_nonzero {
+    ($type:ident, $p:ty, $with:ident $(,)?) => {
+        impl_bytes!(
+            $type,
+            $p,
+            $with,
+            range::Result<Self>,
+            range::result,
+            "range::Error",
+        );
+    };
+}
+
+impl_bytes_ranged!(RangedI8, i8, with_i8);
+impl_bytes_ranged!(RangedI16, i16, with_i16);
+impl_bytes_ranged!(RangedI32, i32, with_i32);
+impl_bytes_ranged!(RangedI64, i64, with_i64);
+impl_bytes_ranged!(RangedI128, i128, with_i128);
+impl_bytes_ranged!(RangedIsize, isize, with_isize);
+impl_bytes_ranged!(RangedU8, u8, with_u8);
+impl_bytes_ranged!(RangedU16, u16, with_u16);
+impl_bytes_ranged!(RangedU32, u32, with_u32);
+impl_bytes_ranged!(RangedU64, u64, with_u64);
+impl_bytes_ranged!(RangedU128, u128, with_u128);
+impl_bytes_ranged!(RangedUsize, usize, with_usize);
+
+impl_bytes_nonzero!(RangedNonZeroI8, i8, with_i8);
+impl_bytes_nonzero!(RangedNonZeroI16, i16, with_i16);
+impl_bytes_nonzero!(RangedNonZeroI32, i32, with_i32);
+impl_bytes_nonzero!(RangedNonZeroI64, i64, with_i64);
+impl_bytes_nonzero!(RangedNonZeroI128, i128, with_i128);
+impl_bytes_nonzero!(RangedNonZeroIsize, isize, with_isize);
+impl_bytes_nonzero!(RangedNonZeroU8, u8, with_u8);
+impl_bytes_nonzero!(RangedNonZeroU16, u16, with_u16);
+impl_bytes_nonzero!(RangedNonZeroU32, u32, with_u32);
+impl_bytes_nonzero!(RangedNonZeroU64, u64, with_u64);
+impl_bytes_nonzero!(RangedNonZeroU128, u128, with_u128);
+impl_bytes_nonzero!(RangedNonZeroUsize, usize, with_usize);
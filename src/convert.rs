@@ -3,12 +3,12 @@ use core::num::{NonZero, TryFromIntError};
 use super::*;
 
 macro_rules! impl_ranged_conversion {
-    ($type:ident, $p:ty $(,)?) => {
+    ($type:ident, $p:ty, $with:ident $(,)?) => {
         impl<const MIN: $p, const MAX: $p> TryFrom<$p> for $type<MIN, MAX> {
             type Error = Error;
 
             fn try_from(primitive: $p) -> Result<Self, Self::Error> {
-                Self::with_primitive(primitive)
+                Self::$with(primitive)
             }
         }
 
@@ -26,9 +26,7 @@ macro_rules! impl_ranged_nonzero_conversion {
             type Error = range::Error;
 
             fn try_from(primitive: $p) -> range::Result<Self> {
-                $r::<MIN, MAX>::with_primitive(primitive)?
-                    .to_ranged_nonzero()
-                    .ok_or(Self::Error::Zero)
+                <Self as RangedInt>::with_primitive(primitive)
             }
         }
 
@@ -177,49 +175,223 @@ macro_rules! impl_nonzero_from_ranged {
     }
 }
 
-impl_ranged_conversion!(RangedI8, i8);
-impl_ranged_conversion!(RangedI16, i16);
-impl_ranged_conversion!(RangedI32, i32);
-impl_ranged_conversion!(RangedI64, i64);
-impl_ranged_conversion!(RangedI128, i128);
-impl_ranged_conversion!(RangedU8, u8);
-impl_ranged_conversion!(RangedU16, u16);
-impl_ranged_conversion!(RangedU32, u32);
-impl_ranged_conversion!(RangedU64, u64);
-impl_ranged_conversion!(RangedU128, u128);
+/// Widen a bound for comparison with a bound of the output's signedness
+macro_rules! widen_bound {
+    (unsigned, unsigned, $value:expr) => {
+        $value as u128
+    };
+    (signed, signed, $value:expr) => {
+        $value as i128
+    };
+    (signed, unsigned, $value:expr) => {
+        i128_to_u128($value as _)
+    };
+    (unsigned, signed, $value:expr) => {
+        u128_to_i128($value as _)
+    };
+}
+
+macro_rules! impl_size_conversion {
+    (
+        $type:ident,
+        $p:ty,
+        $bound:ident;
+        $($out:ident, $out_p:ty, $out_bound:ident, $method:ident);* $(;)?
+    ) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            $(
+                #[doc = concat!("Convert to [`", stringify!($out), "`].")]
+                ///
+                /// ```rust
+                #[doc = concat!("# use ranch::", stringify!($type), ";")]
+                #[doc = concat!("let ranged = ", stringify!($type), "::<0, 2>::new::<1>();")]
+                #[doc = concat!("let expanded: ranch::", stringify!($out), "<0, 4> =")]
+                #[doc = concat!("    ranged.", stringify!($method), "();")]
+                ///
+                /// assert_eq!(Ok(expanded.get()), ranged.get().try_into());
+                /// ```
+                // casts are only trivial for conversions between equal widths
+                #[allow(trivial_numeric_casts)]
+                pub const fn $method<
+                    const OUT_MIN: $out_p,
+                    const OUT_MAX: $out_p,
+                >(self) -> $out<OUT_MIN, OUT_MAX> {
+                    const {
+                        let min = widen_bound!($bound, $out_bound, MIN);
+                        let max = widen_bound!($bound, $out_bound, MAX);
+
+                        if widen_bound!($out_bound, $out_bound, OUT_MIN) > min {
+                            panic!("minimum must be lower or match");
+                        }
+
+                        if widen_bound!($out_bound, $out_bound, OUT_MAX) < max {
+                            panic!("maximum must be higher or match");
+                        }
+                    }
+
+                    $out(self.get() as _)
+                }
+            )*
+        }
+    };
+}
+
+impl_ranged_conversion!(RangedI8, i8, with_i8);
+impl_ranged_conversion!(RangedI16, i16, with_i16);
+impl_ranged_conversion!(RangedI32, i32, with_i32);
+impl_ranged_conversion!(RangedI64, i64, with_i64);
+impl_ranged_conversion!(RangedI128, i128, with_i128);
+impl_ranged_conversion!(RangedIsize, isize, with_isize);
+impl_ranged_conversion!(RangedU8, u8, with_u8);
+impl_ranged_conversion!(RangedU16, u16, with_u16);
+impl_ranged_conversion!(RangedU32, u32, with_u32);
+impl_ranged_conversion!(RangedU64, u64, with_u64);
+impl_ranged_conversion!(RangedU128, u128, with_u128);
+impl_ranged_conversion!(RangedUsize, usize, with_usize);
 
 impl_ranged_nonzero_conversion!(RangedNonZeroI8, i8, RangedI8);
 impl_ranged_nonzero_conversion!(RangedNonZeroI16, i16, RangedI16);
 impl_ranged_nonzero_conversion!(RangedNonZeroI32, i32, RangedI32);
 impl_ranged_nonzero_conversion!(RangedNonZeroI64, i64, RangedI64);
 impl_ranged_nonzero_conversion!(RangedNonZeroI128, i128, RangedI128);
+impl_ranged_nonzero_conversion!(RangedNonZeroIsize, isize, RangedIsize);
 impl_ranged_nonzero_conversion!(RangedNonZeroU8, u8, RangedU8);
 impl_ranged_nonzero_conversion!(RangedNonZeroU16, u16, RangedU16);
 impl_ranged_nonzero_conversion!(RangedNonZeroU32, u32, RangedU32);
 impl_ranged_nonzero_conversion!(RangedNonZeroU64, u64, RangedU64);
 impl_ranged_nonzero_conversion!(RangedNonZeroU128, u128, RangedU128);
+impl_ranged_nonzero_conversion!(RangedNonZeroUsize, usize, RangedUsize);
 
 impl_signed_nonzero_conversion!(RangedI8, i8);
 impl_signed_nonzero_conversion!(RangedI16, i16);
 impl_signed_nonzero_conversion!(RangedI32, i32);
 impl_signed_nonzero_conversion!(RangedI64, i64);
 impl_signed_nonzero_conversion!(RangedI128, i128);
+impl_signed_nonzero_conversion!(RangedIsize, isize);
 impl_unsigned_nonzero_conversion!(RangedU8, u8);
 impl_unsigned_nonzero_conversion!(RangedU16, u16);
 impl_unsigned_nonzero_conversion!(RangedU32, u32);
 impl_unsigned_nonzero_conversion!(RangedU64, u64);
 impl_unsigned_nonzero_conversion!(RangedU128, u128);
+impl_unsigned_nonzero_conversion!(RangedUsize, usize);
 
 impl_nonzero_from_ranged!(RangedI8, i8, RangedNonZeroI8);
 impl_nonzero_from_ranged!(RangedI16, i16, RangedNonZeroI16);
 impl_nonzero_from_ranged!(RangedI32, i32, RangedNonZeroI32);
 impl_nonzero_from_ranged!(RangedI64, i64, RangedNonZeroI64);
 impl_nonzero_from_ranged!(RangedI128, i128, RangedNonZeroI128);
+impl_nonzero_from_ranged!(RangedIsize, isize, RangedNonZeroIsize);
 impl_nonzero_from_ranged!(RangedU8, u8, RangedNonZeroU8);
 impl_nonzero_from_ranged!(RangedU16, u16, RangedNonZeroU16);
 impl_nonzero_from_ranged!(RangedU32, u32, RangedNonZeroU32);
 impl_nonzero_from_ranged!(RangedU64, u64, RangedNonZeroU64);
 impl_nonzero_from_ranged!(RangedU128, u128, RangedNonZeroU128);
+impl_nonzero_from_ranged!(RangedUsize, usize, RangedNonZeroUsize);
+
+impl_size_conversion!(
+    RangedU8,
+    u8,
+    unsigned;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedU16,
+    u16,
+    unsigned;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedU32,
+    u32,
+    unsigned;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedU64,
+    u64,
+    unsigned;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedU128,
+    u128,
+    unsigned;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedUsize,
+    usize,
+    unsigned;
+    RangedU8, u8, unsigned, to_ranged_u8;
+    RangedU16, u16, unsigned, to_ranged_u16;
+    RangedU32, u32, unsigned, to_ranged_u32;
+    RangedU64, u64, unsigned, to_ranged_u64;
+    RangedU128, u128, unsigned, to_ranged_u128;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedI8, i8, signed, to_ranged_i8;
+    RangedI16, i16, signed, to_ranged_i16;
+    RangedI32, i32, signed, to_ranged_i32;
+    RangedI64, i64, signed, to_ranged_i64;
+    RangedI128, i128, signed, to_ranged_i128;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedI8,
+    i8,
+    signed;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedI16,
+    i16,
+    signed;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedI32,
+    i32,
+    signed;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedI64,
+    i64,
+    signed;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedI128,
+    i128,
+    signed;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
+impl_size_conversion!(
+    RangedIsize,
+    isize,
+    signed;
+    RangedU8, u8, unsigned, to_ranged_u8;
+    RangedU16, u16, unsigned, to_ranged_u16;
+    RangedU32, u32, unsigned, to_ranged_u32;
+    RangedU64, u64, unsigned, to_ranged_u64;
+    RangedU128, u128, unsigned, to_ranged_u128;
+    RangedUsize, usize, unsigned, to_ranged_usize;
+    RangedI8, i8, signed, to_ranged_i8;
+    RangedI16, i16, signed, to_ranged_i16;
+    RangedI32, i32, signed, to_ranged_i32;
+    RangedI64, i64, signed, to_ranged_i64;
+    RangedI128, i128, signed, to_ranged_i128;
+    RangedIsize, isize, signed, to_ranged_isize;
+);
 
 impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
     /// Convert to [`RangedU8`].
@@ -2801,66 +2973,6 @@ impl<const MIN: u128, const MAX: u128> RangedU128<MIN, MAX> {
     }
 }
 
-impl<const MIN: i8, const MAX: i8> RangedI8<MIN, MAX> {
-    fn with_primitive(value: i8) -> Result<Self> {
-        Self::with_i8(value)
-    }
-}
-
-impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
-    fn with_primitive(value: u8) -> Result<Self> {
-        Self::with_u8(value)
-    }
-}
-
-impl<const MIN: i16, const MAX: i16> RangedI16<MIN, MAX> {
-    fn with_primitive(value: i16) -> Result<Self> {
-        Self::with_i16(value)
-    }
-}
-
-impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
-    fn with_primitive(value: u16) -> Result<Self> {
-        Self::with_u16(value)
-    }
-}
-
-impl<const MIN: i32, const MAX: i32> RangedI32<MIN, MAX> {
-    fn with_primitive(value: i32) -> Result<Self> {
-        Self::with_i32(value)
-    }
-}
-
-impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
-    fn with_primitive(value: u32) -> Result<Self> {
-        Self::with_u32(value)
-    }
-}
-
-impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
-    fn with_primitive(value: i64) -> Result<Self> {
-        Self::with_i64(value)
-    }
-}
-
-impl<const MIN: u64, const MAX: u64> RangedU64<MIN, MAX> {
-    fn with_primitive(value: u64) -> Result<Self> {
-        Self::with_u64(value)
-    }
-}
-
-impl<const MIN: i128, const MAX: i128> RangedI128<MIN, MAX> {
-    fn with_primitive(value: i128) -> Result<Self> {
-        Self::with_i128(value)
-    }
-}
-
-impl<const MIN: u128, const MAX: u128> RangedU128<MIN, MAX> {
-    fn with_primitive(value: u128) -> Result<Self> {
-        Self::with_u128(value)
-    }
-}
-
 const fn i8_to_u8(value: i8) -> u8 {
     if value < 0 {
         panic!("minimum must be lower or match");
@@ -0,0 +1,510 @@
+use core::{fmt, ops::RangeInclusive};
+
+use as_repr::AsRepr;
+
+use super::{
+    ops::{signed_checked, unsigned_checked},
+    *,
+};
+
+macro_rules! impl_dyn_ranged {
+    (
+        $type:ident,
+        $ranged:ident,
+        $p:ident,
+        $with:ident,
+        $ret:ident,
+        $convert:path,
+        $ok:literal,
+        $neg:literal,
+        $pos:literal $(,)?
+    ) => {
+        #[doc = concat!("[`", stringify!($p), "`] with a minimum and maximum value chosen at runtime")]
+        ///
+        /// For bounds loaded from configuration or user input.  Operations
+        #[doc = concat!("have the same semantics as [`", stringify!($ranged), "`], checked against")]
+        /// the runtime bounds.
+        ///
+        /// ```rust
+        #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($ranged), "};")]
+        /// let (min, max) = (1, 12);
+        #[doc = concat!("let hour = ", stringify!($type), "::with_bounds(min, max, 12).unwrap();")]
+        #[doc = concat!("let ranged: ", stringify!($ranged), "<0, 23> = hour.try_into().unwrap();")]
+        ///
+        /// assert_eq!(hour.saturating_add(1).get(), 12);
+        /// assert_eq!(ranged.get(), 12);
+        /// ```
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        pub struct $type {
+            value: $p,
+            min: $p,
+            max: $p,
+        }
+
+        impl $type {
+            /// Try to create a new ranged integer within `min..=max`.
+            ///
+            /// Returns `Err` if out of bounds, which is always the case when
+            /// `min > max`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("type Value = ", stringify!($type), ";")]
+            ///
+            /// assert_eq!(Value::with_bounds(1, 2, 1).unwrap().get(), 1);
+            /// assert_eq!(Value::with_bounds(1, 2, 0), Err(Error::NegOverflow));
+            /// assert_eq!(Value::with_bounds(1, 2, 3), Err(Error::PosOverflow));
+            /// assert_eq!(Value::with_bounds(2, 1, 2), Err(Error::PosOverflow));
+            /// ```
+            pub const fn with_bounds(
+                min: $p,
+                max: $p,
+                value: impl AsRepr<$p>,
+            ) -> Result<Self> {
+                let value = as_repr::as_repr(value);
+
+                if value < min {
+                    return Err(Error::NegOverflow);
+                }
+
+                if value > max {
+                    return Err(Error::PosOverflow);
+                }
+
+                Ok(Self { value, min, max })
+            }
+
+            /// Try to create a new ranged integer with the same bounds.
+            ///
+            /// Returns `Err` if out of bounds.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(a.with_value(100).unwrap().get(), 100);
+            /// assert_eq!(a.with_value(101), Err(Error::PosOverflow));
+            /// ```
+            pub const fn with_value(
+                self,
+                value: impl AsRepr<$p>,
+            ) -> Result<Self> {
+                Self::with_bounds(self.min, self.max, value)
+            }
+
+            /// Return the contained value as a primitive type.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 42).unwrap();")]
+            ///
+            /// assert_eq!(a.get(), 42);
+            /// ```
+            #[must_use]
+            pub const fn get(self) -> $p {
+                self.value
+            }
+
+            /// Return the inclusive range of valid values.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 42).unwrap();")]
+            ///
+            /// assert_eq!(a.bounds(), 1..=100);
+            /// ```
+            #[must_use]
+            pub const fn bounds(self) -> RangeInclusive<$p> {
+                RangeInclusive::new(self.min, self.max)
+            }
+
+            /// Try to convert into a ranged integer with const bounds.
+            ///
+            /// Only succeeds if the runtime bounds are within `MIN..=MAX`, so
+            /// that any value with these bounds would fit, not just the
+            /// current one.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($ranged), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 12, 6).unwrap();")]
+            ///
+            /// assert_eq!(
+            ///     a.to_ranged::<0, 23>(),
+            #[doc = concat!("    Ok(", stringify!($ranged), "::new::<6>()),")]
+            /// );
+            /// assert_eq!(a.to_ranged::<2, 23>(), Err(Error::NegOverflow));
+            /// assert_eq!(a.to_ranged::<0, 11>(), Err(Error::PosOverflow));
+            /// ```
+            pub const fn to_ranged<const MIN: $p, const MAX: $p>(
+                self,
+            ) -> Result<$ranged<MIN, MAX>> {
+                if self.min < MIN {
+                    return Err(Error::NegOverflow);
+                }
+
+                if self.max > MAX {
+                    return Err(Error::PosOverflow);
+                }
+
+                $ranged::<MIN, MAX>::$with(self.value)
+            }
+
+            /// Add two ranged integers together.
+            ///
+            /// Returns an error on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(a.checked_add(5).map(|x| x.get()), ", $ok, "(55));")]
+            #[doc = concat!("assert_eq!(a.checked_add(51), ", $pos, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            // comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            pub const fn checked_add(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+                let Some(value) = self.value.checked_add(other) else {
+                    return $convert(Err(if other < 0 {
+                        Error::NegOverflow
+                    } else {
+                        Error::PosOverflow
+                    }));
+                };
+
+                $convert(self.with_value(value))
+            }
+
+            /// Add two ranged integers together.
+            ///
+            /// Saturates at the bounds on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(a.saturating_add(5).get(), 55);
+            #[doc = concat!("assert_eq!(a.saturating_add(", stringify!($p), "::MAX).get(), 100);")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_add(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                self.saturate(self.value.saturating_add(other))
+            }
+
+            /// Subtract a ranged integer from another.
+            ///
+            /// Returns an error on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(a.checked_sub(5).map(|x| x.get()), ", $ok, "(45));")]
+            #[doc = concat!("assert_eq!(a.checked_sub(50), ", $neg, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            // comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            pub const fn checked_sub(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+                let Some(value) = self.value.checked_sub(other) else {
+                    return $convert(Err(if other < 0 {
+                        Error::PosOverflow
+                    } else {
+                        Error::NegOverflow
+                    }));
+                };
+
+                $convert(self.with_value(value))
+            }
+
+            /// Subtract a ranged integer from another.
+            ///
+            /// Saturates at the bounds on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(a.saturating_sub(5).get(), 45);
+            #[doc = concat!("assert_eq!(a.saturating_sub(", stringify!($p), "::MAX).get(), 1);")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_sub(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                self.saturate(self.value.saturating_sub(other))
+            }
+
+            /// Multiply two ranged integers together.
+            ///
+            /// Returns an error on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(a.checked_mul(2).map(|x| x.get()), ", $ok, "(100));")]
+            #[doc = concat!("assert_eq!(a.checked_mul(3), ", $pos, ");")]
+            #[doc = concat!("assert_eq!(a.checked_mul(0), ", $neg, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            // comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            pub const fn checked_mul(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+                let Some(value) = self.value.checked_mul(other) else {
+                    return $convert(Err(if (self.value < 0) ^ (other < 0) {
+                        Error::NegOverflow
+                    } else {
+                        Error::PosOverflow
+                    }));
+                };
+
+                $convert(self.with_value(value))
+            }
+
+            /// Multiply two ranged integers together.
+            ///
+            /// Saturates at the bounds on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(a.saturating_mul(2).get(), 100);
+            #[doc = concat!("assert_eq!(a.saturating_mul(", stringify!($p), "::MAX).get(), 100);")]
+            /// assert_eq!(a.saturating_mul(0).get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_mul(self, other: impl AsRepr<$p>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                self.saturate(self.value.saturating_mul(other))
+            }
+
+            /// Raise a ranged integer to an integer power.
+            ///
+            /// Returns an error on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 10).unwrap();")]
+            ///
+            #[doc = concat!("assert_eq!(a.checked_pow(2).map(|x| x.get()), ", $ok, "(100));")]
+            #[doc = concat!("assert_eq!(a.checked_pow(3), ", $pos, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            // comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            pub const fn checked_pow(
+                self,
+                other: impl AsRepr<u32>,
+            ) -> $ret<Self> {
+                let other = as_repr::as_repr(other);
+                let Some(value) = self.value.checked_pow(other) else {
+                    return $convert(Err(if self.value < 0 && other % 2 == 1 {
+                        Error::NegOverflow
+                    } else {
+                        Error::PosOverflow
+                    }));
+                };
+
+                $convert(self.with_value(value))
+            }
+
+            /// Raise a ranged integer to an integer power.
+            ///
+            /// Saturates at the bounds on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(1, 100, 10).unwrap();")]
+            ///
+            /// assert_eq!(a.saturating_pow(2).get(), 100);
+            /// assert_eq!(a.saturating_pow(100).get(), 100);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_pow(self, other: impl AsRepr<u32>) -> Self {
+                let other = as_repr::as_repr(other);
+
+                self.saturate(self.value.saturating_pow(other))
+            }
+
+            /// Checked integer division.
+            ///
+            /// Returns an error on overflow; [`Quotient::Nan`] if `rhs == 0`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Error, Quotient};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(10, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(
+            ///     a.checked_div(2).map(|x| x.map(|x| x.get())),
+            #[doc = concat!("    ", $ok, "(Quotient::Number(25)),")]
+            /// );
+            #[doc = concat!("assert_eq!(a.checked_div(0), ", $ok, "(Quotient::Nan));")]
+            #[doc = concat!("assert_eq!(a.checked_div(10), ", $neg, ");")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_div(
+                self,
+                rhs: impl AsRepr<$p>,
+            ) -> $ret<Quotient<Self>> {
+                let rhs = as_repr::as_repr(rhs);
+
+                if rhs == 0 {
+                    return $convert(Ok(Quotient::Nan));
+                }
+
+                // only overflows when dividing the minimum by negative one
+                let Some(value) = self.value.checked_div(rhs) else {
+                    return $convert(Err(Error::PosOverflow));
+                };
+
+                $convert(match self.with_value(value) {
+                    Ok(value) => Ok(Quotient::Number(value)),
+                    Err(e) => Err(e),
+                })
+            }
+
+            /// Saturating integer division.
+            ///
+            /// Saturates at the bounds on overflow, and returns
+            /// [`Quotient::Nan`] if `rhs` is 0.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Quotient};")]
+            #[doc = concat!("let a = ", stringify!($type), "::with_bounds(10, 100, 50).unwrap();")]
+            ///
+            /// assert_eq!(a.saturating_div(2).map(|x| x.get()), Quotient::Number(25));
+            /// assert_eq!(a.saturating_div(0), Quotient::Nan);
+            /// assert_eq!(a.saturating_div(10).map(|x| x.get()), Quotient::Number(10));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_div(
+                self,
+                rhs: impl AsRepr<$p>,
+            ) -> Quotient<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                if rhs == 0 {
+                    return Quotient::Nan;
+                }
+
+                Quotient::Number(self.saturate(self.value.saturating_div(rhs)))
+            }
+
+            const fn saturate(self, value: $p) -> Self {
+                Self {
+                    value: if value < self.min {
+                        self.min
+                    } else if value > self.max {
+                        self.max
+                    } else {
+                        value
+                    },
+                    ..self
+                }
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> From<$ranged<MIN, MAX>> for $type {
+            fn from(ranged: $ranged<MIN, MAX>) -> Self {
+                Self {
+                    value: ranged.get(),
+                    min: MIN,
+                    max: MAX,
+                }
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> TryFrom<$type> for $ranged<MIN, MAX> {
+            type Error = Error;
+
+            fn try_from(dynamic: $type) -> Result<Self> {
+                dynamic.to_ranged()
+            }
+        }
+
+        impl From<$type> for $p {
+            fn from(dynamic: $type) -> Self {
+                dynamic.get()
+            }
+        }
+
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+    };
+}
+
+macro_rules! impl_dyn_ranged_unsigned {
+    ($type:ident, $ranged:ident, $p:ident, $with:ident $(,)?) => {
+        impl_dyn_ranged!(
+            $type,
+            $ranged,
+            $p,
+            $with,
+            Option,
+            unsigned_checked,
+            "Some",
+            "None",
+            "None",
+        );
+    };
+}
+
+macro_rules! impl_dyn_ranged_signed {
+    ($type:ident, $ranged:ident, $p:ident, $with:ident $(,)?) => {
+        impl_dyn_ranged!(
+            $type,
+            $ranged,
+            $p,
+            $with,
+            Result,
+            signed_checked,
+            "Ok",
+            "Err(Error::NegOverflow)",
+            "Err(Error::PosOverflow)",
+        );
+    };
+}
+
+impl_dyn_ranged_unsigned!(DynRangedU8, RangedU8, u8, with_u8);
+impl_dyn_ranged_unsigned!(DynRangedU16, RangedU16, u16, with_u16);
+impl_dyn_ranged_unsigned!(DynRangedU32, RangedU32, u32, with_u32);
+impl_dyn_ranged_unsigned!(DynRangedU64, RangedU64, u64, with_u64);
+impl_dyn_ranged_unsigned!(DynRangedU128, RangedU128, u128, with_u128);
+impl_dyn_ranged_unsigned!(DynRangedUsize, RangedUsize, usize, with_usize);
+
+impl_dyn_ranged_signed!(DynRangedI8, RangedI8, i8, with_i8);
+impl_dyn_ranged_signed!(DynRangedI16, RangedI16, i16, with_i16);
+impl_dyn_ranged_signed!(DynRangedI32, RangedI32, i32, with_i32);
+impl_dyn_ranged_signed!(DynRangedI64, RangedI64, i64, with_i64);
+impl_dyn_ranged_signed!(DynRangedI128, RangedI128, i128, with_i128);
+impl_dyn_ranged_signed!(DynRangedIsize, RangedIsize, isize, with_isize);
@@ -0,0 +1,206 @@
+/// Evaluate an expression of ranged integers, inferring the output range.
+///
+/// Chains [`add_ranged`], [`sub_ranged`] and [`mul_ranged`] with every
+/// intermediate range calculated for you.  Operands are the names of ranged
+/// integers in scope, listed with their types, and integer literals.  `+`, `-`
+/// and `*` follow the usual precedence, and can be grouped with parentheses.
+///
+/// All operands share the `Ranged*` type given first, and their types must be
+/// concrete (not depending on generic parameters).
+///
+/// ```rust
+/// # use ranch::{expr, RangedI32};
+/// type Percent = RangedI32<0, 100>;
+/// type Offset = RangedI32<-10, 10>;
+///
+/// let a = Percent::new::<50>();
+/// let b = Percent::new::<20>();
+/// let c = Offset::new::<-5>();
+/// let output = expr!(
+///     RangedI32; a: Percent, b: Percent, c: Offset => a * b + c
+/// );
+/// let doubled = expr!(RangedI32; c: Offset => 2 * (c - 1));
+///
+/// assert_eq!(output, RangedI32::<-10, 10010>::new::<995>());
+/// assert_eq!(doubled, RangedI32::<-22, 18>::new::<-12>());
+/// ```
+///
+/// Prefix with `type` to name the output type of an expression of types.
+///
+/// ```rust
+/// # use ranch::{expr, RangedU16};
+/// type Digit = RangedU16<0, 9>;
+/// type TwoDigits = expr!(type RangedU16 => Digit * 10 + Digit);
+///
+/// assert_eq!(TwoDigits::MAX, RangedU16::<0, 99>::MAX);
+/// ```
+///
+/// Division isn't supported, since [`div_ranged`] returns a
+/// [`Quotient`](crate::Quotient).
+///
+/// ```rust,compile_fail
+/// # use ranch::{expr, RangedI32};
+/// type Value = RangedI32<1, 10>;
+///
+/// let a = Value::new::<5>();
+/// let _ = expr!(RangedI32; a: Value => a / 2);
+/// ```
+///
+/// [`add_ranged`]: crate::RangedI32::add_ranged
+/// [`sub_ranged`]: crate::RangedI32::sub_ranged
+/// [`mul_ranged`]: crate::RangedI32::mul_ranged
+/// [`div_ranged`]: crate::RangedI32::div_ranged
+#[macro_export]
+macro_rules! expr {
+    (type $f:ident => $($expr:tt)+) => {
+        $crate::expr!(@sum ty [$f] [] [] [] $($expr)+)
+    };
+    ($f:ident; $($name:ident: $type:ty),+ $(,)? => $($expr:tt)+) => {{
+        $(
+            // operand names double as their types while evaluating
+            #[allow(non_camel_case_types, dead_code)]
+            type $name = $type;
+        )+
+
+        $crate::expr!(@sum val [$f] [] [] [] $($expr)+)
+    }};
+    // split on the last `+` / `-` outside parentheses, where `* -` is negation
+    (
+        @sum $m:ident [$f:ident] [$($b:tt)*] [$($o:tt)?] [$($a:tt)*]
+        * - $($r:tt)*
+    ) => {
+        $crate::expr!(@sum $m [$f] [$($b)*] [$($o)?] [$($a)* * -] $($r)*)
+    };
+    (@sum $m:ident [$f:ident] [$($b:tt)*] [] [$($a:tt)+] + $($r:tt)*) => {
+        $crate::expr!(@sum $m [$f] [$($a)+] [+] [] $($r)*)
+    };
+    (@sum $m:ident [$f:ident] [$($b:tt)*] [] [$($a:tt)+] - $($r:tt)*) => {
+        $crate::expr!(@sum $m [$f] [$($a)+] [-] [] $($r)*)
+    };
+    (
+        @sum $m:ident [$f:ident] [$($b:tt)*] [$o:tt] [$($a:tt)+]
+        + $($r:tt)*
+    ) => {
+        $crate::expr!(@sum $m [$f] [$($b)* $o $($a)+] [+] [] $($r)*)
+    };
+    (
+        @sum $m:ident [$f:ident] [$($b:tt)*] [$o:tt] [$($a:tt)+]
+        - $($r:tt)*
+    ) => {
+        $crate::expr!(@sum $m [$f] [$($b)* $o $($a)+] [-] [] $($r)*)
+    };
+    (
+        @sum $m:ident [$f:ident] [$($b:tt)*] [$($o:tt)?] [$($a:tt)*]
+        $t:tt $($r:tt)*
+    ) => {
+        $crate::expr!(@sum $m [$f] [$($b)*] [$($o)?] [$($a)* $t] $($r)*)
+    };
+    (@sum $m:ident [$f:ident] [] [] [$($a:tt)+]) => {
+        $crate::expr!(@product $m [$f] [] [] [] $($a)+)
+    };
+    (@sum $m:ident [$f:ident] [$($b:tt)+] [$o:tt] [$($a:tt)+]) => {
+        $crate::expr!(@op $m [$f] [$o] [$($b)+] [$($a)+])
+    };
+    // split on the last `*` outside parentheses
+    (
+        @product $m:ident [$f:ident] [$($b:tt)*] [$($o:tt)?] [$($a:tt)*]
+        / $($r:tt)*
+    ) => {
+        ::core::compile_error!(
+            "division isn't supported by `expr!`, use `div_ranged`",
+        )
+    };
+    (@product $m:ident [$f:ident] [$($b:tt)*] [] [$($a:tt)+] * $($r:tt)*) => {
+        $crate::expr!(@product $m [$f] [$($a)+] [*] [] $($r)*)
+    };
+    (
+        @product $m:ident [$f:ident] [$($b:tt)*] [$o:tt] [$($a:tt)+]
+        * $($r:tt)*
+    ) => {
+        $crate::expr!(@product $m [$f] [$($b)* $o $($a)+] [*] [] $($r)*)
+    };
+    (
+        @product $m:ident [$f:ident] [$($b:tt)*] [$($o:tt)?] [$($a:tt)*]
+        $t:tt $($r:tt)*
+    ) => {
+        $crate::expr!(@product $m [$f] [$($b)*] [$($o)?] [$($a)* $t] $($r)*)
+    };
+    (@product $m:ident [$f:ident] [] [] [$($a:tt)+]) => {
+        $crate::expr!(@atom $m [$f] $($a)+)
+    };
+    (@product $m:ident [$f:ident] [$($b:tt)+] [$o:tt] [$($a:tt)+]) => {
+        $crate::expr!(@op $m [$f] [$o] [$($b)+] [$($a)+])
+    };
+    // operands
+    (@atom $m:ident [$f:ident] ($($e:tt)+)) => {
+        $crate::expr!(@sum $m [$f] [] [] [] $($e)+)
+    };
+    (@atom ty [$f:ident] $name:ident) => { $name };
+    (@atom val [$f:ident] $name:ident) => { $name };
+    (@atom ty [$f:ident] $lit:literal) => {
+        $crate::$f<{ $lit }, { $lit }>
+    };
+    (@atom val [$f:ident] $lit:literal) => {
+        $crate::$f::<{ $lit }, { $lit }>::new::<{ $lit }>()
+    };
+    (@atom ty [$f:ident] - $lit:literal) => {
+        $crate::$f<{ -$lit }, { -$lit }>
+    };
+    (@atom val [$f:ident] - $lit:literal) => {
+        $crate::$f::<{ -$lit }, { -$lit }>::new::<{ -$lit }>()
+    };
+    (@atom $m:ident [$f:ident] $($t:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unsupported operand in `expr!`: ",
+            ::core::stringify!($($t)*),
+        ))
+    };
+    // operations
+    (@op val [$f:ident] [$o:tt] [$($l:tt)+] [$($r:tt)+]) => {{
+        let output: $crate::expr!(@op ty [$f] [$o] [$($l)+] [$($r)+]) =
+            $crate::expr!(@method [$o] [
+                $crate::expr!(@sum val [$f] [] [] [] $($l)+),
+                $crate::expr!(@sum val [$f] [] [] [] $($r)+),
+            ]);
+
+        output
+    }};
+    (@method [+] [$l:expr, $r:expr $(,)?]) => { $l.add_ranged($r) };
+    (@method [-] [$l:expr, $r:expr $(,)?]) => { $l.sub_ranged($r) };
+    (@method [*] [$l:expr, $r:expr $(,)?]) => { $l.mul_ranged($r) };
+    (@op ty [$f:ident] [+] $l:tt $r:tt) => {
+        $crate::expr!(@bounds [$f] add_min add_max $l $r)
+    };
+    (@op ty [$f:ident] [-] $l:tt $r:tt) => {
+        $crate::expr!(@bounds [$f] sub_min sub_max $l $r)
+    };
+    (@op ty [$f:ident] [*] $l:tt $r:tt) => {
+        $crate::expr!(@bounds [$f] mul_min mul_max $l $r)
+    };
+    (
+        @bounds [$f:ident] $min:ident $max:ident
+        [$($l:tt)+] [$($r:tt)+]
+    ) => {
+        $crate::$f<
+            {
+                $crate::__private::bounds::$f::$min(
+                    $crate::expr!(@bound MIN [$f] $($l)+),
+                    $crate::expr!(@bound MAX [$f] $($l)+),
+                    $crate::expr!(@bound MIN [$f] $($r)+),
+                    $crate::expr!(@bound MAX [$f] $($r)+),
+                )
+            },
+            {
+                $crate::__private::bounds::$f::$max(
+                    $crate::expr!(@bound MIN [$f] $($l)+),
+                    $crate::expr!(@bound MAX [$f] $($l)+),
+                    $crate::expr!(@bound MIN [$f] $($r)+),
+                    $crate::expr!(@bound MAX [$f] $($r)+),
+                )
+            },
+        >
+    };
+    (@bound $bound:ident [$f:ident] $($e:tt)+) => {
+        <$crate::expr!(@sum ty [$f] [] [] [] $($e)+)>::$bound.get()
+    };
+}
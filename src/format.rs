@@ -20,6 +20,21 @@ macro_rules! impl_quotient_fmt {
     };
 }
 
+macro_rules! impl_wrapper_fmt {
+    ($type:ident, [$($Trait:ident),* $(,)?] $(,)?) => {
+        $(
+            impl<T> fmt::$Trait for $type<T>
+                where T: fmt::$Trait
+            {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! impl_ranged_fmt {
     ($type:ident, $primitive:ty, [$($Trait:ident),* $(,)?] $(,)?) => {
         $(
@@ -43,6 +58,20 @@ impl_quotient_fmt!(
     ],
 );
 
+impl_wrapper_fmt!(
+    Wrapping,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
+
+impl_wrapper_fmt!(
+    Saturating,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
+
 impl_ranged_fmt!(
     RangedU8,
     u8,
@@ -82,6 +111,13 @@ impl_ranged_fmt!(
         Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
     ],
 );
+impl_ranged_fmt!(
+    RangedUsize,
+    usize,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
 
 impl_ranged_fmt!(
     RangedI8,
@@ -122,6 +158,13 @@ impl_ranged_fmt!(
         Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
     ],
 );
+impl_ranged_fmt!(
+    RangedIsize,
+    isize,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
 
 impl_ranged_fmt!(
     RangedNonZeroU8,
@@ -194,6 +237,13 @@ impl_ranged_fmt!(
         Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
     ],
 );
+impl_ranged_fmt!(
+    RangedNonZeroUsize,
+    usize,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
 
 impl_ranged_fmt!(
     RangedNonZeroI128,
@@ -202,3 +252,10 @@ impl_ranged_fmt!(
         Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
     ],
 );
+impl_ranged_fmt!(
+    RangedNonZeroIsize,
+    isize,
+    [
+        Debug, Display, Binary, Octal, LowerHex, UpperHex, LowerExp, UpperExp,
+    ],
+);
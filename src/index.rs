@@ -0,0 +1,105 @@
+//! Indexing arrays by ranged integers
+//!
+//! When the maximum of a ranged index is less than the length of an array,
+//! indexing can never go out of bounds, so no bounds check is needed.  This is
+//! checked at compile time.
+//!
+//! ```rust
+//! # use ranch::{index::RangedIndex, RangedU8, RangedUsize};
+//! let mut array = [1, 2, 3, 4];
+//! let index = RangedU8::<0, 3>::new::<2>();
+//!
+//! *array.get_ranged_mut(index) *= 10;
+//! assert_eq!(*array.get_ranged(index), 30);
+//!
+//! // `RangedUsize` can also be used with the indexing operator
+//! assert_eq!(array[RangedUsize::<1, 3>::new::<3>()], 4);
+//! ```
+//!
+//! Does not compile:
+//!
+//! ```rust,compile_fail
+//! # use ranch::{index::RangedIndex, RangedU8};
+//! let array = [1, 2, 3, 4];
+//!
+//! array.get_ranged(RangedU8::<0, 4>::new::<0>());
+//! ```
+
+use core::ops::{Index, IndexMut};
+
+use super::*;
+
+/// Extension trait for indexing by a ranged integer without bounds checks
+pub trait RangedIndex<I> {
+    /// The type of the elements
+    type Output;
+
+    /// Get a reference to the element at `index`.
+    ///
+    /// Won't compile if the index's maximum is out of bounds.
+    fn get_ranged(&self, index: I) -> &Self::Output;
+
+    /// Get a mutable reference to the element at `index`.
+    ///
+    /// Won't compile if the index's maximum is out of bounds.
+    fn get_ranged_mut(&mut self, index: I) -> &mut Self::Output;
+}
+
+macro_rules! impl_ranged_index {
+    ($type:ident, $p:ty $(,)?) => {
+        // casts are only trivial for `u128` and `usize`
+        #[allow(trivial_numeric_casts)]
+        impl<T, const N: usize, const MIN: $p, const MAX: $p>
+            RangedIndex<$type<MIN, MAX>> for [T; N]
+        {
+            type Output = T;
+
+            fn get_ranged(&self, index: $type<MIN, MAX>) -> &T {
+                const {
+                    if MAX as u128 >= N as u128 {
+                        panic!("maximum must be less than the array length");
+                    }
+                }
+
+                // saturate if there's a bug and a value out of range
+                &self[index.get().min(MAX) as usize]
+            }
+
+            fn get_ranged_mut(&mut self, index: $type<MIN, MAX>) -> &mut T {
+                const {
+                    if MAX as u128 >= N as u128 {
+                        panic!("maximum must be less than the array length");
+                    }
+                }
+
+                // saturate if there's a bug and a value out of range
+                &mut self[index.get().min(MAX) as usize]
+            }
+        }
+    };
+}
+
+impl_ranged_index!(RangedU8, u8);
+impl_ranged_index!(RangedU16, u16);
+impl_ranged_index!(RangedU32, u32);
+impl_ranged_index!(RangedU64, u64);
+impl_ranged_index!(RangedU128, u128);
+impl_ranged_index!(RangedUsize, usize);
+
+impl<T, const N: usize, const MIN: usize, const MAX: usize>
+    Index<RangedUsize<MIN, MAX>> for [T; N]
+{
+    type Output = T;
+
+    fn index(&self, index: RangedUsize<MIN, MAX>) -> &T {
+        self.get_ranged(index)
+    }
+}
+
+impl<T, const N: usize, const MIN: usize, const MAX: usize>
+    IndexMut<RangedUsize<MIN, MAX>> for [T; N]
+{
+    fn index_mut(&mut self, index: RangedUsize<MIN, MAX>) -> &mut T {
+        self.get_ranged_mut(index)
+    }
+}
@@ -0,0 +1,394 @@
+use core::{fmt, hash::Hash};
+
+use super::*;
+
+pub(crate) mod sealed {
+    pub trait Sealed: Sized {
+        /// Get the next larger value, if any.
+        fn step_up(self) -> Option<Self>;
+
+        /// Get the next smaller value, if any.
+        fn step_down(self) -> Option<Self>;
+
+        /// Count the values from `self` to `end` (inclusive), if it fits in a
+        /// `usize`.
+        fn count_to(self, end: Self) -> Option<usize>;
+    }
+}
+
+/// A ranged integer
+///
+/// Implemented for every `Ranged*` and `RangedNonZero*` type, allowing code to
+/// be generic over any ranged integer.  Unlike the inherent methods, which
+/// return an [`Option`] or a [`Result`] depending on the type, every checked
+/// operation on this trait returns a [`range::Result`].
+///
+/// ```rust
+/// # use ranch::{RangedInt, RangedNonZeroI16, RangedU8};
+/// fn bounds<T: RangedInt>(value: T) -> [T::Primitive; 3] {
+///     [T::MIN.get(), value.get(), T::MAX.get()]
+/// }
+///
+/// assert_eq!(bounds(RangedU8::<1, 12>::new::<4>()), [1, 4, 12]);
+/// assert_eq!(
+///     bounds(RangedNonZeroI16::<-8, 8>::new::<-3>()),
+///     [-8, -3, 8],
+/// );
+/// ```
+pub trait RangedInt:
+    Copy + Eq + Ord + Hash + fmt::Debug + fmt::Display + sealed::Sealed
+{
+    /// The underlying primitive integer type
+    type Primitive: Copy + Eq + Ord + Hash + fmt::Debug + fmt::Display;
+
+    /// The size of this integer type in bits.
+    const BITS: u32;
+    /// The largest value that can be represented by this integer type.
+    const MAX: Self;
+    /// The smallest value that can be represented by this integer type.
+    const MIN: Self;
+
+    /// Try to create a new ranged integer.
+    ///
+    /// ```rust
+    /// # use ranch::{range::Error, RangedInt, RangedNonZeroU8};
+    /// type Hour = RangedNonZeroU8<1, 12>;
+    ///
+    /// assert_eq!(Hour::with_primitive(3), Ok(Hour::new::<3>()));
+    /// assert_eq!(Hour::with_primitive(0), Err(Error::Zero));
+    /// assert_eq!(Hour::with_primitive(13), Err(Error::PosOverflow));
+    /// ```
+    fn with_primitive(value: Self::Primitive) -> range::Result<Self>;
+
+    /// Return the contained value as a primitive type.
+    fn get(self) -> Self::Primitive;
+
+    /// Add a primitive to `self`.
+    ///
+    /// ```rust
+    /// # use ranch::{range::Error, RangedInt, RangedU8};
+    /// let a = RangedU8::<1, 12>::new::<11>();
+    ///
+    /// assert_eq!(RangedInt::checked_add(a, 1), Ok(RangedU8::new::<12>()));
+    /// assert_eq!(RangedInt::checked_add(a, 2), Err(Error::PosOverflow));
+    /// ```
+    fn checked_add(self, other: Self::Primitive) -> range::Result<Self>;
+
+    /// Subtract a primitive from `self`.
+    ///
+    /// ```rust
+    /// # use ranch::{range::Error, RangedInt, RangedNonZeroI8};
+    /// let a = RangedNonZeroI8::<-4, 4>::new::<2>();
+    ///
+    /// assert_eq!(RangedInt::checked_sub(a, 2), Err(Error::Zero));
+    /// assert_eq!(RangedInt::checked_sub(a, 7), Err(Error::NegOverflow));
+    /// ```
+    fn checked_sub(self, other: Self::Primitive) -> range::Result<Self>;
+
+    /// Multiply `self` by a primitive.
+    fn checked_mul(self, other: Self::Primitive) -> range::Result<Self>;
+
+    /// Divide `self` by a primitive.
+    ///
+    /// Returns [`Quotient::Nan`] if `rhs == 0`.
+    fn checked_div(self, rhs: Self::Primitive)
+    -> range::Result<Quotient<Self>>;
+
+    /// Raise `self` to an integer power.
+    fn checked_pow(self, exp: u32) -> range::Result<Self>;
+
+    /// Add a primitive to `self`, saturating at the bounds.
+    ///
+    /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+    /// positive overflow.  For non-zero types, a result of zero saturates to
+    /// the non-zero value closest to zero on the same side as `self`.
+    ///
+    /// ```rust
+    /// # use ranch::{RangedInt, RangedNonZeroI8};
+    /// let a = RangedNonZeroI8::<-4, 4>::new::<2>();
+    ///
+    /// assert_eq!(RangedInt::saturating_add(a, 9).get(), 4);
+    /// assert_eq!(RangedInt::saturating_add(a, -2).get(), 1);
+    /// assert_eq!(RangedInt::saturating_add(a, -3).get(), -1);
+    /// ```
+    fn saturating_add(self, other: Self::Primitive) -> Self;
+
+    /// Subtract a primitive from `self`, saturating at the bounds.
+    ///
+    /// See [`RangedInt::saturating_add()`] for how saturation works.
+    fn saturating_sub(self, other: Self::Primitive) -> Self;
+
+    /// Multiply `self` by a primitive, saturating at the bounds.
+    ///
+    /// See [`RangedInt::saturating_add()`] for how saturation works.
+    fn saturating_mul(self, other: Self::Primitive) -> Self;
+
+    /// Divide `self` by a primitive, saturating at the bounds.
+    ///
+    /// Returns [`Quotient::Nan`] if `rhs == 0`.  See
+    /// [`RangedInt::saturating_add()`] for how saturation works.
+    fn saturating_div(self, rhs: Self::Primitive) -> Quotient<Self>;
+
+    /// Raise `self` to an integer power, saturating at the bounds.
+    ///
+    /// See [`RangedInt::saturating_add()`] for how saturation works.
+    fn saturating_pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_ranged_int_ops {
+    ($p:ty) => {
+        fn checked_add(self, other: $p) -> range::Result<Self> {
+            let Some(value) = self.get().checked_add(other) else {
+                return Err(overflow(self.get().saturating_add(other)));
+            };
+
+            Self::with_primitive(value)
+        }
+
+        fn checked_sub(self, other: $p) -> range::Result<Self> {
+            let Some(value) = self.get().checked_sub(other) else {
+                return Err(overflow(self.get().saturating_sub(other)));
+            };
+
+            Self::with_primitive(value)
+        }
+
+        fn checked_mul(self, other: $p) -> range::Result<Self> {
+            let Some(value) = self.get().checked_mul(other) else {
+                return Err(overflow(self.get().saturating_mul(other)));
+            };
+
+            Self::with_primitive(value)
+        }
+
+        fn checked_div(self, rhs: $p) -> range::Result<Quotient<Self>> {
+            if rhs == 0 {
+                return Ok(Quotient::Nan);
+            }
+
+            let Some(value) = self.get().checked_div(rhs) else {
+                return Err(range::Error::PosOverflow);
+            };
+
+            Self::with_primitive(value).map(Quotient::Number)
+        }
+
+        fn checked_pow(self, exp: u32) -> range::Result<Self> {
+            let Some(value) = self.get().checked_pow(exp) else {
+                return Err(overflow(self.get().saturating_pow(exp)));
+            };
+
+            Self::with_primitive(value)
+        }
+
+        fn saturating_add(self, other: $p) -> Self {
+            self.saturate(self.get().saturating_add(other))
+        }
+
+        fn saturating_sub(self, other: $p) -> Self {
+            self.saturate(self.get().saturating_sub(other))
+        }
+
+        fn saturating_mul(self, other: $p) -> Self {
+            self.saturate(self.get().saturating_mul(other))
+        }
+
+        fn saturating_div(self, rhs: $p) -> Quotient<Self> {
+            if rhs == 0 {
+                return Quotient::Nan;
+            }
+
+            Quotient::Number(self.saturate(self.get().saturating_div(rhs)))
+        }
+
+        fn saturating_pow(self, exp: u32) -> Self {
+            self.saturate(self.get().saturating_pow(exp))
+        }
+    };
+}
+
+macro_rules! impl_ranged_int {
+    ($type:ident, $p:ident, $with:ident $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> sealed::Sealed for $type<MIN, MAX> {
+            fn step_up(self) -> Option<Self> {
+                Self::$with(self.get().checked_add(1)?).ok()
+            }
+
+            fn step_down(self) -> Option<Self> {
+                Self::$with(self.get().checked_sub(1)?).ok()
+            }
+
+            fn count_to(self, end: Self) -> Option<usize> {
+                let count = end.get().abs_diff(self.get());
+
+                usize::try_from(count).ok()?.checked_add(1)
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> RangedInt for $type<MIN, MAX> {
+            type Primitive = $p;
+
+            const BITS: u32 = Self::BITS;
+            const MAX: Self = Self::MAX;
+            const MIN: Self = Self::MIN;
+
+            impl_ranged_int_ops!($p);
+
+            fn with_primitive(value: $p) -> range::Result<Self> {
+                Ok(Self::$with(value)?)
+            }
+
+            fn get(self) -> $p {
+                self.get()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Iterate over every value of this type, from [`Self::MIN`] to
+            /// [`Self::MAX`].
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let mut values = ", stringify!($type), "::<1, 3>::all();")]
+            ///
+            /// assert_eq!(values.size_hint(), (3, Some(3)));
+            /// assert_eq!(values.next().map(|x| x.get()), Some(1));
+            /// assert_eq!(values.next_back().map(|x| x.get()), Some(3));
+            /// assert_eq!(values.next().map(|x| x.get()), Some(2));
+            /// assert_eq!(values.next(), None);
+            /// ```
+            pub const fn all() -> range::Iter<Self> {
+                range::Iter::all()
+            }
+
+            fn saturate(self, value: $p) -> Self {
+                match <Self as RangedInt>::with_primitive(value) {
+                    Ok(value) => value,
+                    Err(range::Error::NegOverflow) => Self::MIN,
+                    Err(_) => Self::MAX,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_ranged_int_nonzero {
+    ($type:ident, $p:ident, $with:ident, $side:expr $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> sealed::Sealed for $type<MIN, MAX> {
+            fn step_up(self) -> Option<Self> {
+                let value = self.get().checked_add(1)?;
+                // step over zero
+                let value = if value == 0 { value.checked_add(1)? } else { value };
+
+                Self::with_primitive(value).ok()
+            }
+
+            fn step_down(self) -> Option<Self> {
+                let value = self.get().checked_sub(1)?;
+                // step over zero
+                let value = if value == 0 { value.checked_sub(1)? } else { value };
+
+                Self::with_primitive(value).ok()
+            }
+
+            // `self` comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            fn count_to(self, end: Self) -> Option<usize> {
+                let count = end.get().abs_diff(self.get());
+                let count = usize::try_from(count).ok()?;
+
+                if self.get() < 0 && end.get() > 0 {
+                    Some(count)
+                } else {
+                    count.checked_add(1)
+                }
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> RangedInt for $type<MIN, MAX> {
+            type Primitive = $p;
+
+            const BITS: u32 = Self::BITS;
+            const MAX: Self = Self::MAX;
+            const MIN: Self = Self::MIN;
+
+            impl_ranged_int_ops!($p);
+
+            fn with_primitive(value: $p) -> range::Result<Self> {
+                range::result(Self::$with(value))
+            }
+
+            fn get(self) -> $p {
+                self.get()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Iterate over every value of this type, from [`Self::MIN`] to
+            /// [`Self::MAX`].
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let mut values = ", stringify!($type), "::<1, 3>::all();")]
+            ///
+            /// assert_eq!(values.size_hint(), (3, Some(3)));
+            /// assert_eq!(values.next().map(|x| x.get()), Some(1));
+            /// assert_eq!(values.next_back().map(|x| x.get()), Some(3));
+            /// assert_eq!(values.next().map(|x| x.get()), Some(2));
+            /// assert_eq!(values.next(), None);
+            /// ```
+            pub const fn all() -> range::Iter<Self> {
+                range::Iter::all()
+            }
+
+            fn saturate(self, value: $p) -> Self {
+                match <Self as RangedInt>::with_primitive(value) {
+                    Ok(value) => value,
+                    Err(range::Error::NegOverflow) => Self::MIN,
+                    Err(range::Error::PosOverflow) => Self::MAX,
+                    // step off of zero towards the side `self` is on
+                    Err(range::Error::Zero) => self.saturate($side(self.get())),
+                }
+            }
+        }
+    };
+}
+
+impl_ranged_int!(RangedU8, u8, with_u8);
+impl_ranged_int!(RangedU16, u16, with_u16);
+impl_ranged_int!(RangedU32, u32, with_u32);
+impl_ranged_int!(RangedU64, u64, with_u64);
+impl_ranged_int!(RangedU128, u128, with_u128);
+impl_ranged_int!(RangedUsize, usize, with_usize);
+impl_ranged_int!(RangedI8, i8, with_i8);
+impl_ranged_int!(RangedI16, i16, with_i16);
+impl_ranged_int!(RangedI32, i32, with_i32);
+impl_ranged_int!(RangedI64, i64, with_i64);
+impl_ranged_int!(RangedI128, i128, with_i128);
+impl_ranged_int!(RangedIsize, isize, with_isize);
+
+impl_ranged_int_nonzero!(RangedNonZeroU8, u8, with_u8, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroU16, u16, with_u16, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroU32, u32, with_u32, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroU64, u64, with_u64, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroU128, u128, with_u128, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroUsize, usize, with_usize, |_| 1);
+impl_ranged_int_nonzero!(RangedNonZeroI8, i8, with_i8, i8::signum);
+impl_ranged_int_nonzero!(RangedNonZeroI16, i16, with_i16, i16::signum);
+impl_ranged_int_nonzero!(RangedNonZeroI32, i32, with_i32, i32::signum);
+impl_ranged_int_nonzero!(RangedNonZeroI64, i64, with_i64, i64::signum);
+impl_ranged_int_nonzero!(RangedNonZeroI128, i128, with_i128, i128::signum);
+impl_ranged_int_nonzero!(RangedNonZeroIsize, isize, with_isize, isize::signum);
+
+/// Get the overflow error from the saturated primitive result of an overflowing
+/// operation.
+fn overflow<T>(saturated: T) -> range::Error
+where
+    T: Ord + Default,
+{
+    if saturated > T::default() {
+        range::Error::PosOverflow
+    } else {
+        range::Error::NegOverflow
+    }
+}
@@ -7,11 +7,52 @@
 //! crate is sort of like a combination of similar crates [deranged] and [ux].
 //!
 //! This crate heavily leverages the type system to allow for powerful ranged
-//! integer mathematics, covering [arbitrary `i{N}` / `u{N}` types](bitwise),
-//! [unit integers](mod@unit), non-zero divisions, [ASCII](ascii), and const
+//! integer mathematics, covering [arbitrary `i{N}` / `u{N}` types](bitwise)
+//! (which can be [bit-packed](pack) or used in a [`bitfield!`]), [unit
+//! integers](mod@unit), non-zero divisions, [ASCII](ascii), and const
 //! operations.  Enable the _**`serde`**_ feature for range-validated
 //! deserialization / serialization (implements [`Serialize`] and
-//! [`Deserialize`] for `Ranged*` types).
+//! [`Deserialize`] for `Ranged*` types, with errors naming the accepted range,
+//! and [`serde::as_string`] for string encoding).  Enable the _**`rand`**_
+//! feature to generate uniformly distributed random ranged integers (implements
+//! [`Distribution`] for [`StandardUniform`], and [`SampleUniform`]).  Enable
+//! the _**`arbitrary`**_ and _**`proptest`**_ features for fuzzing and property
+//! testing (implements [`Arbitrary`] and proptest's [`Arbitrary`][proptest],
+//! generating only values in range, which proptest shrinks toward `MIN`).
+//! Enable the _**`num-traits`**_ feature for generic numeric code (implements
+//! [`Bounded`], [`ToPrimitive`], [`FromPrimitive`], [`NumCast`] and
+//! `Saturating`, plus the `Checked*` and `Saturating*` operation traits for
+//! `Ranged*` types).  Enable the _**`bytemuck`**_ feature for range-validated
+//! casting of byte buffers (implements [`CheckedBitPattern`], [`NoUninit`], and
+//! [`Zeroable`] for types with a minimum of zero, since other ranges containing
+//! zero can't be selected by a trait impl without unstable const generics).
+//! Enable the _**`zerocopy`**_ feature for [`IntoBytes`], [`KnownLayout`] and
+//! [`Immutable`] (`TryFromBytes` can't be derived with range validation, so use
+//! _**`bytemuck`**_ for validated casts).
+//!
+//! Use [`ranged!`] to create ranged integers from literals, and
+//! [`range_type!`] to pick the smallest fitting type for a range.
+//!
+//! ```rust
+//! # use ranch::{ranged, range_type, RangedNonZeroU8};
+//! type Weekday = range_type!(1..=7);
+//!
+//! assert_eq!(ranged!(5, 1..=7), Weekday::new::<5>());
+//! assert_eq!(Weekday::MAX, RangedNonZeroU8::<1, 7>::new::<7>());
+//! ```
+//!
+//! When bounds are only known at runtime (e.g. loaded from configuration), use
+//! [`DynRangedU32`] and friends, which carry their bounds with the value, and
+//! convert into `Ranged*` types once the bounds are known to fit.
+//!
+//! ```rust
+//! # use ranch::{DynRangedU8, RangedU8};
+//! let (min, max) = (1, 12);
+//! let hour = DynRangedU8::with_bounds(min, max, 9).unwrap();
+//!
+//! assert_eq!(hour.checked_add(4), None);
+//! assert_eq!(hour.to_ranged::<0, 23>(), Ok(RangedU8::new::<9>()));
+//! ```
 //!
 //! # Types of operations
 //!
@@ -75,6 +116,21 @@
 //! );
 //! ```
 //!
+//! Wrap values in [`Saturating`] to use saturating operations with operators,
+//! or in [`Wrapping`] to instead wrap around within the range.
+//!
+//! ```rust
+//! # use ranch::{RangedI32, Saturating, Wrapping};
+//! assert_eq!(
+//!     Saturating(RangedI32::<2, 7>::new::<2>()) + 6,
+//!     Saturating(RangedI32::<2, 7>::new::<7>()),
+//! );
+//! assert_eq!(
+//!     Wrapping(RangedI32::<2, 7>::new::<2>()) + 6,
+//!     Wrapping(RangedI32::<2, 7>::new::<2>()),
+//! );
+//! ```
+//!
 //! ## Constant
 //!
 //! Constant operations add a constant value and modify the output's range
@@ -113,12 +169,57 @@
 //! );
 //! ```
 //!
+//! Use [`expr!`] to chain ranged operations without writing out every
+//! intermediate range:
+//!
+//! ```rust
+//! # use ranch::{expr, RangedI32};
+//! type Input = RangedI32<2, 7>;
+//!
+//! let a = Input::new::<2>();
+//! let output = expr!(RangedI32; a: Input => a * a + 6);
+//!
+//! assert_eq!(output, RangedI32::<10, 55>::new::<10>());
+//! ```
+//!
+//! The output bounds of each operation are also available as `const fn`s in
+//! [`bounds`], for naming them in your own type aliases.
+//!
+//! When the output doesn't fit the operands' primitive, widening operations
+//! return the next larger ranged integer instead:
+//!
+//! ```rust
+//! # use ranch::{RangedU8, RangedU16};
+//! let a = RangedU8::<0, 200>::new::<200>();
+//! let b = RangedU16::<0, 1000>::new::<1000>();
+//! let product: RangedU16<0, 40000> = a.widening_mul_ranged(a);
+//! let sum: RangedU16<0, 1200> = a.add_wide_ranged(b);
+//!
+//! assert_eq!(product.get(), 40000);
+//! assert_eq!(sum.get(), 1200);
+//! ```
+//!
 //! [deranged]: https://docs.rs/crate/deranged
 //! [ux]: https://docs.rs/crate/ux
 //! [validate the range once]: RangedI32::with_i32()
 //! [at compile time]: RangedI32::new()
 //! [`Serialize`]: serde_core::Serialize
 //! [`Deserialize`]: serde_core::Deserialize
+//! [`Distribution`]: ::rand::distr::Distribution
+//! [`StandardUniform`]: ::rand::distr::StandardUniform
+//! [`SampleUniform`]: ::rand::distr::uniform::SampleUniform
+//! [`Arbitrary`]: ::arbitrary::Arbitrary
+//! [proptest]: ::proptest::arbitrary::Arbitrary
+//! [`Bounded`]: ::num_traits::Bounded
+//! [`ToPrimitive`]: ::num_traits::ToPrimitive
+//! [`FromPrimitive`]: ::num_traits::FromPrimitive
+//! [`NumCast`]: ::num_traits::NumCast
+//! [`CheckedBitPattern`]: ::bytemuck::CheckedBitPattern
+//! [`NoUninit`]: ::bytemuck::NoUninit
+//! [`Zeroable`]: ::bytemuck::Zeroable
+//! [`IntoBytes`]: ::zerocopy::IntoBytes
+//! [`KnownLayout`]: ::zerocopy::KnownLayout
+//! [`Immutable`]: ::zerocopy::Immutable
 
 #![doc(
     html_logo_url = "https://ardaku.github.io/mm/logo.svg",
@@ -154,18 +255,40 @@
     rustdoc::redundant_explicit_links
 )]
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 pub mod ascii;
 mod assertions;
 mod assign;
+mod bit_ops;
+mod bitfield;
 pub mod bitwise;
+pub mod bounds;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod bytes;
 mod convert;
+mod dynamic;
 mod error;
+mod expr;
 mod format;
 mod impl_ascii;
+pub mod index;
+mod int;
+mod literal;
+mod neg;
+mod niche;
+#[cfg(feature = "num-traits")]
+mod num_traits;
 mod ops;
 mod ord;
+pub mod pack;
 pub mod parsing;
+#[cfg(feature = "proptest")]
+mod proptest;
 mod quotient;
+#[cfg(feature = "rand")]
+mod rand;
 pub mod range;
 mod ranged {
     pub(super) mod i128;
@@ -173,11 +296,13 @@ mod ranged {
     pub(super) mod i32;
     pub(super) mod i64;
     pub(super) mod i8;
+    pub(super) mod isize;
     pub(super) mod u128;
     pub(super) mod u16;
     pub(super) mod u32;
     pub(super) mod u64;
     pub(super) mod u8;
+    pub(super) mod usize;
 }
 mod nonzero {
     pub(super) mod i128;
@@ -185,30 +310,67 @@ mod nonzero {
     pub(super) mod i32;
     pub(super) mod i64;
     pub(super) mod i8;
+    pub(super) mod isize;
     pub(super) mod u128;
     pub(super) mod u16;
     pub(super) mod u32;
     pub(super) mod u64;
     pub(super) mod u8;
+    pub(super) mod usize;
 }
 mod repr;
+mod saturating;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+mod sum;
 pub mod unit;
+mod widening;
+mod wrapping;
+mod wrapping_ops;
 
+#[cfg(feature = "rand")]
+pub use self::rand::UniformRanged;
 pub use self::{
+    dynamic::{
+        DynRangedI8, DynRangedI16, DynRangedI32, DynRangedI64, DynRangedI128,
+        DynRangedIsize, DynRangedU8, DynRangedU16, DynRangedU32, DynRangedU64,
+        DynRangedU128, DynRangedUsize,
+    },
     error::{Error, Result},
+    int::RangedInt,
+    niche::{Niche, OptionRanged},
     nonzero::{
         i8::RangedNonZeroI8, i16::RangedNonZeroI16, i32::RangedNonZeroI32,
-        i64::RangedNonZeroI64, i128::RangedNonZeroI128, u8::RangedNonZeroU8,
-        u16::RangedNonZeroU16, u32::RangedNonZeroU32, u64::RangedNonZeroU64,
-        u128::RangedNonZeroU128,
+        i64::RangedNonZeroI64, i128::RangedNonZeroI128,
+        isize::RangedNonZeroIsize, u8::RangedNonZeroU8, u16::RangedNonZeroU16,
+        u32::RangedNonZeroU32, u64::RangedNonZeroU64, u128::RangedNonZeroU128,
+        usize::RangedNonZeroUsize,
     },
     quotient::Quotient,
     ranged::{
         i8::RangedI8, i16::RangedI16, i32::RangedI32, i64::RangedI64,
-        i128::RangedI128, u8::RangedU8, u16::RangedU16, u32::RangedU32,
-        u64::RangedU64, u128::RangedU128,
+        i128::RangedI128, isize::RangedIsize, u8::RangedU8, u16::RangedU16,
+        u32::RangedU32, u64::RangedU64, u128::RangedU128, usize::RangedUsize,
     },
+    saturating::Saturating,
+    wrapping::Wrapping,
 };
+#[doc(hidden)]
+pub mod __private {
+    /// Bounds modules by ranged integer type, for [`expr!`](crate::expr!)
+    pub mod bounds {
+        pub use crate::bounds::{
+            i8 as RangedI8, i16 as RangedI16, i32 as RangedI32,
+            i64 as RangedI64, i128 as RangedI128, isize as RangedIsize,
+            u8 as RangedU8, u16 as RangedU16, u32 as RangedU32,
+            u64 as RangedU64, u128 as RangedU128, usize as RangedUsize,
+        };
+    }
+
+    pub use super::{
+        bitfield::Backing,
+        literal::{Bound, Pick, Select, range_kind},
+    };
+}
+
 use crate::parsing::{Error as ParsingError, Result as ParsingResult};
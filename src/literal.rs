@@ -0,0 +1,244 @@
+/// Create a ranged integer from a literal, validated at compile time.
+///
+/// The type can either be specified explicitly, or inferred from a range with
+/// [`range_type!`](crate::range_type!).
+///
+/// ```rust
+/// # use ranch::{ranged, RangedI32, RangedNonZeroU8, RangedI8};
+/// assert_eq!(ranged!(5: RangedI32<2, 7>), RangedI32::<2, 7>::new::<5>());
+/// assert_eq!(ranged!(5, 2..=7), RangedNonZeroU8::<2, 7>::new::<5>());
+/// assert_eq!(ranged!(-1, -4..=7), RangedI8::<-4, 7>::new::<-1>());
+/// ```
+///
+/// Does not compile:
+///
+/// ```rust,compile_fail
+/// let _ = ranch::ranged!(8, 2..=7);
+/// ```
+#[macro_export]
+macro_rules! ranged {
+    ($value:literal: $type:ty) => {
+        <$type>::new::<{ $value }>()
+    };
+    ($value:literal, $($range:tt)*) => {
+        <$crate::range_type!($($range)*)>::new::<{ $value }>()
+    };
+}
+
+/// Get the smallest ranged integer type that fits an inclusive range.
+///
+/// Unsigned types are preferred when the range has no negative numbers, and
+/// non-zero types are chosen when the range excludes zero.
+///
+/// ```rust
+/// # use ranch::{
+/// #     range_type, RangedI16, RangedNonZeroI8, RangedNonZeroU8, RangedU16,
+/// #     RangedU8,
+/// # };
+/// type Digit = range_type!(0..=9);
+/// type Weekday = range_type!(1..=7);
+/// type Offset = range_type!(-4..=-1);
+/// type Port = range_type!(0..=65535);
+/// type Celsius = range_type!(-273..=1000);
+///
+/// assert_eq!(Digit::new::<9>(), RangedU8::<0, 9>::new::<9>());
+/// assert_eq!(Weekday::new::<1>(), RangedNonZeroU8::<1, 7>::new::<1>());
+/// assert_eq!(Offset::new::<-2>(), RangedNonZeroI8::<-4, -1>::new::<-2>());
+/// assert_eq!(Port::MAX, RangedU16::<0, 65535>::MAX);
+/// assert_eq!(Celsius::MIN, RangedI16::<-273, 1000>::MIN);
+/// ```
+///
+/// Does not compile:
+///
+/// ```rust,compile_fail
+/// type Empty = ranch::range_type!(7..=2);
+///
+/// let _ = Empty::MIN;
+/// ```
+#[macro_export]
+macro_rules! range_type {
+    (@bounds $min:expr, $max:expr) => {
+        <$crate::__private::Select<{ $crate::__private::range_kind($min, $max) }>
+            as $crate::__private::Pick<(
+                $crate::range_type!(@type RangedU8, u8, $min, $max),
+                $crate::range_type!(@type RangedU16, u16, $min, $max),
+                $crate::range_type!(@type RangedU32, u32, $min, $max),
+                $crate::range_type!(@type RangedU64, u64, $min, $max),
+                $crate::range_type!(@type RangedU128, u128, $min, $max),
+                $crate::range_type!(@type RangedI8, i8, $min, $max),
+                $crate::range_type!(@type RangedI16, i16, $min, $max),
+                $crate::range_type!(@type RangedI32, i32, $min, $max),
+                $crate::range_type!(@type RangedI64, i64, $min, $max),
+                $crate::range_type!(@type RangedI128, i128, $min, $max),
+                $crate::range_type!(@type RangedNonZeroU8, u8, $min, $max),
+                $crate::range_type!(@type RangedNonZeroU16, u16, $min, $max),
+                $crate::range_type!(@type RangedNonZeroU32, u32, $min, $max),
+                $crate::range_type!(@type RangedNonZeroU64, u64, $min, $max),
+                $crate::range_type!(@type RangedNonZeroU128, u128, $min, $max),
+                $crate::range_type!(@type RangedNonZeroI8, i8, $min, $max),
+                $crate::range_type!(@type RangedNonZeroI16, i16, $min, $max),
+                $crate::range_type!(@type RangedNonZeroI32, i32, $min, $max),
+                $crate::range_type!(@type RangedNonZeroI64, i64, $min, $max),
+                $crate::range_type!(@type RangedNonZeroI128, i128, $min, $max),
+            )>>::Out
+    };
+    (@type $type:ident, $p:ty, $min:expr, $max:expr) => {
+        $crate::$type<
+            { $crate::__private::Bound::bits($min) as $p },
+            { $crate::__private::Bound::bits($max) as $p },
+        >
+    };
+    (- $min:literal ..= - $max:literal) => {
+        $crate::range_type!(
+            @bounds
+            $crate::__private::Bound::neg($min),
+            $crate::__private::Bound::neg($max)
+        )
+    };
+    (- $min:literal ..= $max:literal) => {
+        $crate::range_type!(
+            @bounds
+            $crate::__private::Bound::neg($min),
+            $crate::__private::Bound::pos($max)
+        )
+    };
+    ($min:literal ..= - $max:literal) => {
+        $crate::range_type!(
+            @bounds
+            $crate::__private::Bound::pos($min),
+            $crate::__private::Bound::neg($max)
+        )
+    };
+    ($min:literal ..= $max:literal) => {
+        $crate::range_type!(
+            @bounds
+            $crate::__private::Bound::pos($min),
+            $crate::__private::Bound::pos($max)
+        )
+    };
+}
+
+/// A literal bound of a range, with its sign parsed separately
+#[derive(Copy, Clone, Debug)]
+pub struct Bound {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl Bound {
+    /// Create a non-negative bound.
+    pub const fn pos(magnitude: u128) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    /// Create a non-positive bound.
+    pub const fn neg(magnitude: u128) -> Self {
+        Self {
+            negative: magnitude != 0,
+            magnitude,
+        }
+    }
+
+    /// Get the two's complement bits, to be truncated to the target type.
+    pub const fn bits(self) -> u128 {
+        if self.negative {
+            self.magnitude.wrapping_neg()
+        } else {
+            self.magnitude
+        }
+    }
+
+    /// Check if `self` is less than `other`.
+    pub(crate) const fn less_than(self, other: Self) -> bool {
+        match (self.negative, other.negative) {
+            (true, false) => true,
+            (false, true) => false,
+            (true, true) => self.magnitude > other.magnitude,
+            (false, false) => self.magnitude < other.magnitude,
+        }
+    }
+
+    /// Check if the bound fits in a signed integer with `bits` bits.
+    const fn fits_signed(self, bits: u32) -> bool {
+        let limit = 1 << (bits - 1);
+
+        if self.negative {
+            self.magnitude <= limit
+        } else {
+            self.magnitude < limit
+        }
+    }
+}
+
+/// Type selected by index from a tuple of candidate types
+#[derive(Copy, Clone, Debug)]
+pub struct Select<const KIND: u8>;
+
+/// Pick the output type from a tuple of candidate types
+pub trait Pick<T> {
+    /// Selected type
+    type Out;
+}
+
+macro_rules! impl_pick {
+    ($($kind:literal => $out:ident),* $(,)?) => {
+        $(
+            impl<
+                T0, T1, T2, T3, T4, T5, T6, T7, T8, T9,
+                T10, T11, T12, T13, T14, T15, T16, T17, T18, T19,
+            > Pick<(
+                T0, T1, T2, T3, T4, T5, T6, T7, T8, T9,
+                T10, T11, T12, T13, T14, T15, T16, T17, T18, T19,
+            )> for Select<$kind> {
+                type Out = $out;
+            }
+        )*
+    };
+}
+
+impl_pick!(
+    0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4,
+    5 => T5, 6 => T6, 7 => T7, 8 => T8, 9 => T9,
+    10 => T10, 11 => T11, 12 => T12, 13 => T13, 14 => T14,
+    15 => T15, 16 => T16, 17 => T17, 18 => T18, 19 => T19,
+);
+
+/// Get the index of the smallest type that fits the range, in the order of
+/// the candidates listed in [`range_type!`](crate::range_type!).
+pub const fn range_kind(min: Bound, max: Bound) -> u8 {
+    const WIDTHS: [u32; 5] = [8, 16, 32, 64, 128];
+
+    if max.less_than(min) {
+        panic!("MIN cannot be more than MAX");
+    }
+
+    let nonzero = if min.negative {
+        max.negative
+    } else {
+        min.magnitude != 0
+    };
+    let offset = if nonzero { 10 } else { 0 };
+    let mut index = 0;
+
+    while index < WIDTHS.len() {
+        let bits = WIDTHS[index];
+        let fits = if min.negative {
+            min.fits_signed(bits) && max.fits_signed(bits)
+        } else {
+            bits == u128::BITS || max.magnitude >> bits == 0
+        };
+
+        if fits {
+            let signed = if min.negative { 5 } else { 0 };
+
+            return offset + signed + index as u8;
+        }
+
+        index += 1;
+    }
+
+    panic!("range does not fit in any integer type")
+}
@@ -0,0 +1,529 @@
+use core::{num::NonZero, ops::Neg};
+
+use super::*;
+
+macro_rules! impl_neg {
+    ($type:ident, $p:ty, $with:ident, $unsigned:ident, $u:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Neg for $type<MIN, MAX> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.checked_neg().expect("out of range")
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Negate `self`.
+            ///
+            /// Returns an [`Error`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            #[doc = concat!("let c = ", stringify!($type), "::<-5, 10>::new::<6>();")]
+            ///
+            /// assert_eq!(a.checked_neg().unwrap().get(), 3);
+            /// assert_eq!(b.checked_neg(), Err(Error::PosOverflow));
+            /// assert_eq!(c.checked_neg(), Err(Error::NegOverflow));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_neg(self) -> Result<Self> {
+                let Some(value) = self.get().checked_neg() else {
+                    return Err(Error::PosOverflow);
+                };
+
+                Self::$with(value)
+            }
+
+            /// Negate `self`.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.saturating_neg().get(), 3);
+            /// assert_eq!(b.saturating_neg().get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_neg(self) -> Self {
+                match Self::$with(self.get().saturating_neg()) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// Returns an [`Error`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.checked_abs().unwrap().get(), 3);
+            /// assert_eq!(b.checked_abs(), Err(Error::PosOverflow));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_abs(self) -> Result<Self> {
+                let Some(value) = self.get().checked_abs() else {
+                    return Err(Error::PosOverflow);
+                };
+
+                Self::$with(value)
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// Returns [`Self::MAX`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.saturating_abs().get(), 3);
+            /// assert_eq!(b.saturating_abs().get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_abs(self) -> Self {
+                match Self::$with(self.get().saturating_abs()) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Negate `self`, flipping the range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<-3, 7> = a.neg_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<-3, 6> = a.neg_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn neg_ranged<const OUTPUT_MIN: $p, const OUTPUT_MAX: $p>(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    if Self::neg_bound(MAX) != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if Self::neg_bound(MIN) != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(-self.get())
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-7, -2>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 7> = a.abs_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            ///
+            #[doc = concat!("let output: ", stringify!($type), "<2, 7> = b.abs_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<1, 7> = a.abs_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn abs_ranged<const OUTPUT_MIN: $p, const OUTPUT_MAX: $p>(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = if MIN >= 0 {
+                        (MIN, MAX)
+                    } else if MAX <= 0 {
+                        (Self::neg_bound(MAX), Self::neg_bound(MIN))
+                    } else if Self::neg_bound(MIN) > MAX {
+                        (0, Self::neg_bound(MIN))
+                    } else {
+                        (0, MAX)
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get().abs())
+            }
+
+            /// Negate a bound of the range, which must fit in the output.
+            const fn neg_bound(bound: $p) -> $p {
+                match bound.checked_neg() {
+                    Some(bound) => bound,
+                    None => panic!(concat!(
+                        "output range doesn't fit in `",
+                        stringify!($p),
+                        "`",
+                    )),
+                }
+            }
+
+            /// Get the absolute value of `self` as an unsigned ranged integer.
+            ///
+            /// Unlike [`Self::abs_ranged()`], this never overflows.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($unsigned), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<{ ", stringify!($p), "::MIN }, 3>::MIN;")]
+            #[doc = concat!("let output: ", stringify!($unsigned), "<0, { ", stringify!($p), "::MIN.unsigned_abs() }> =")]
+            ///     a.unsigned_abs();
+            ///
+            #[doc = concat!("assert_eq!(output.get(), ", stringify!($p), "::MIN.unsigned_abs());")]
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($unsigned), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($unsigned), "<1, 7> = a.unsigned_abs();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn unsigned_abs<
+                const OUTPUT_MIN: $u,
+                const OUTPUT_MAX: $u,
+            >(
+                self,
+            ) -> $unsigned<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = if MIN >= 0 {
+                        (MIN.unsigned_abs(), MAX.unsigned_abs())
+                    } else if MAX <= 0 {
+                        (MAX.unsigned_abs(), MIN.unsigned_abs())
+                    } else if MIN.unsigned_abs() > MAX.unsigned_abs() {
+                        (0, MIN.unsigned_abs())
+                    } else {
+                        (0, MAX.unsigned_abs())
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $unsigned(self.get().unsigned_abs())
+            }
+        }
+    };
+}
+
+macro_rules! impl_neg_nonzero {
+    ($type:ident, $p:ty, $ranged:ident, $unsigned:ident, $u:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Neg for $type<MIN, MAX> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.checked_neg().expect("out of range")
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Negate `self`.
+            ///
+            /// Returns an [`Error`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.checked_neg().unwrap().get(), 3);
+            /// assert_eq!(b.checked_neg(), Err(Error::PosOverflow));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_neg(self) -> Result<Self> {
+                match self.to_ranged().checked_neg() {
+                    Ok(value) => Ok(Self::from_nonzero_ranged(value)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Negate `self`.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.saturating_neg().get(), 3);
+            /// assert_eq!(b.saturating_neg().get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_neg(self) -> Self {
+                Self::from_nonzero_ranged(self.to_ranged().saturating_neg())
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// Returns an [`Error`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.checked_abs().unwrap().get(), 3);
+            /// assert_eq!(b.checked_abs(), Err(Error::PosOverflow));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_abs(self) -> Result<Self> {
+                match self.to_ranged().checked_abs() {
+                    Ok(value) => Ok(Self::from_nonzero_ranged(value)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// Returns [`Self::MAX`] on overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-10, 5>::new::<-3>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-10, 5>::new::<-6>();")]
+            ///
+            /// assert_eq!(a.saturating_abs().get(), 3);
+            /// assert_eq!(b.saturating_abs().get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_abs(self) -> Self {
+                Self::from_nonzero_ranged(self.to_ranged().saturating_abs())
+            }
+
+            /// Negate `self`, flipping the range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<-3, 7> = a.neg_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<-3, 6> = a.neg_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn neg_ranged<const OUTPUT_MIN: $p, const OUTPUT_MAX: $p>(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                $type::from_nonzero_ranged(
+                    self.to_ranged().neg_ranged::<OUTPUT_MIN, OUTPUT_MAX>(),
+                )
+            }
+
+            /// Get the absolute value of `self`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<1, 7> = a.abs_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 7> = a.abs_ranged();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn abs_ranged<const OUTPUT_MIN: $p, const OUTPUT_MAX: $p>(
+                self,
+            ) -> $type<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = if MIN > 0 {
+                        (MIN, MAX)
+                    } else if MAX < 0 {
+                        (Self::neg_bound(MAX), Self::neg_bound(MIN))
+                    } else if Self::neg_bound(MIN) > MAX {
+                        (1, Self::neg_bound(MIN))
+                    } else {
+                        (1, MAX)
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                let Some(value) = NonZero::new(self.get().abs()) else {
+                    unreachable!()
+                };
+
+                $type(value)
+            }
+
+            /// Negate a bound of the range, which must fit in the output.
+            const fn neg_bound(bound: $p) -> $p {
+                match bound.checked_neg() {
+                    Some(bound) => bound,
+                    None => panic!(concat!(
+                        "output range doesn't fit in `",
+                        stringify!($p),
+                        "`",
+                    )),
+                }
+            }
+
+            /// Get the absolute value of `self` as an unsigned ranged integer.
+            ///
+            /// Unlike [`Self::abs_ranged()`], this never overflows.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($unsigned), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<{ ", stringify!($p), "::MIN }, 3>::MIN;")]
+            #[doc = concat!("let output: ", stringify!($unsigned), "<1, { ", stringify!($p), "::MIN.unsigned_abs() }> =")]
+            ///     a.unsigned_abs();
+            ///
+            #[doc = concat!("assert_eq!(output.get(), ", stringify!($p), "::MIN.unsigned_abs());")]
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($unsigned), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<-7, 3>::new::<-5>();")]
+            #[doc = concat!("let output: ", stringify!($unsigned), "<2, 7> = a.unsigned_abs();")]
+            ///
+            /// assert_eq!(output.get(), 5);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn unsigned_abs<
+                const OUTPUT_MIN: $u,
+                const OUTPUT_MAX: $u,
+            >(
+                self,
+            ) -> $unsigned<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (min, max) = if MIN > 0 {
+                        (MIN.unsigned_abs(), MAX.unsigned_abs())
+                    } else if MAX < 0 {
+                        (MAX.unsigned_abs(), MIN.unsigned_abs())
+                    } else if MIN.unsigned_abs() > MAX.unsigned_abs() {
+                        (1, MIN.unsigned_abs())
+                    } else {
+                        (1, MAX.unsigned_abs())
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $unsigned(self.to_nonzero().unsigned_abs())
+            }
+
+            /// Convert from a ranged integer known to be non-zero.
+            const fn from_nonzero_ranged(ranged: $ranged<MIN, MAX>) -> Self {
+                let Some(value) = NonZero::new(ranged.get()) else {
+                    unreachable!()
+                };
+
+                Self(value)
+            }
+        }
+    };
+}
+
+impl_neg!(RangedI8, i8, with_i8, RangedU8, u8);
+impl_neg!(RangedI16, i16, with_i16, RangedU16, u16);
+impl_neg!(RangedI32, i32, with_i32, RangedU32, u32);
+impl_neg!(RangedI64, i64, with_i64, RangedU64, u64);
+impl_neg!(RangedI128, i128, with_i128, RangedU128, u128);
+impl_neg!(RangedIsize, isize, with_isize, RangedUsize, usize);
+
+impl_neg_nonzero!(RangedNonZeroI8, i8, RangedI8, RangedNonZeroU8, u8);
+impl_neg_nonzero!(RangedNonZeroI16, i16, RangedI16, RangedNonZeroU16, u16);
+impl_neg_nonzero!(RangedNonZeroI32, i32, RangedI32, RangedNonZeroU32, u32);
+impl_neg_nonzero!(RangedNonZeroI64, i64, RangedI64, RangedNonZeroU64, u64);
+impl_neg_nonzero!(RangedNonZeroI128, i128, RangedI128, RangedNonZeroU128, u128);
+impl_neg_nonzero!(
+    RangedNonZeroIsize,
+    isize,
+    RangedIsize,
+    RangedNonZeroUsize,
+    usize
+);
@@ -0,0 +1,245 @@
+use core::{cmp::Ordering, fmt};
+
+use super::*;
+
+/// A ranged integer type with an unused primitive value
+///
+/// For `Ranged*` types, the unused value is the primitive's maximum if the
+/// range doesn't include it, otherwise the primitive's minimum.  Using a type
+/// that covers every value of its primitive won't compile.  For
+/// `RangedNonZero*` types, the unused value is zero.
+///
+/// ```rust
+/// # use ranch::{Niche, RangedI8, RangedNonZeroU8, RangedU8};
+/// assert_eq!(RangedU8::<0, 100>::NICHE, u8::MAX);
+/// assert_eq!(RangedI8::<-100, { i8::MAX }>::NICHE, i8::MIN);
+/// assert_eq!(RangedNonZeroU8::<1, { u8::MAX }>::NICHE, 0);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use ranch::{Niche, RangedU8};
+/// let _ = RangedU8::<0, { u8::MAX }>::NICHE;
+/// ```
+pub trait Niche: RangedInt {
+    /// The primitive value that's out of range.
+    const NICHE: Self::Primitive;
+}
+
+/// An optional ranged integer, the same size as the primitive integer
+///
+/// Works like an `Option<T>`, but stores `None` as [`Niche::NICHE`].
+///
+/// ```rust
+/// # use core::mem::size_of;
+/// # use ranch::{OptionRanged, RangedU8};
+/// type Percent = RangedU8<0, 100>;
+///
+/// assert_eq!(size_of::<Option<Percent>>(), 2);
+/// assert_eq!(size_of::<OptionRanged<Percent>>(), 1);
+///
+/// let mut reading = OptionRanged::some(Percent::new::<42>());
+///
+/// assert_eq!(reading.map(|percent| percent.get() * 2), Some(84));
+/// assert_eq!(reading.take(), Some(Percent::new::<42>()));
+/// assert_eq!(reading.unwrap_or(Percent::MIN), Percent::MIN);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct OptionRanged<T: Niche>(T::Primitive);
+
+impl<T: Niche> OptionRanged<T> {
+    /// No value.
+    pub const NONE: Self = Self(T::NICHE);
+
+    /// Create an optional ranged integer containing `value`.
+    pub fn some(value: T) -> Self {
+        Self(value.get())
+    }
+
+    /// Convert to an [`Option`].
+    pub fn get(self) -> Option<T> {
+        T::with_primitive(self.0).ok()
+    }
+
+    /// Return true if there's a value.
+    pub fn is_some(self) -> bool {
+        self.get().is_some()
+    }
+
+    /// Return true if there's no value.
+    pub fn is_none(self) -> bool {
+        self.get().is_none()
+    }
+
+    /// Return the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a custom message if there's no value.
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        self.get().expect(msg)
+    }
+
+    /// Return the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no value.
+    #[track_caller]
+    pub fn unwrap(self) -> T {
+        self.get()
+            .expect("called `OptionRanged::unwrap()` on a `None` value")
+    }
+
+    /// Return the value, or `default` if there's no value.
+    pub fn unwrap_or(self, default: T) -> T {
+        self.get().unwrap_or(default)
+    }
+
+    /// Return the value, or compute it from a closure if there's no value.
+    pub fn unwrap_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.get().unwrap_or_else(f)
+    }
+
+    /// Map the value by applying a function to it.
+    pub fn map<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.get().map(f)
+    }
+
+    /// Map the value by applying a function to it, or return `default` if
+    /// there's no value.
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.get().map_or(default, f)
+    }
+
+    /// Call a function with the value, returning `None` if there's no value.
+    pub fn and_then<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        self.get().and_then(f)
+    }
+
+    /// Keep the value only if `predicate` returns true.
+    pub fn filter<P>(self, predicate: P) -> Self
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        self.get().filter(predicate).into()
+    }
+
+    /// Transform into a [`Result<T, E>`], mapping no value to `Err(err)`.
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        self.get().ok_or(err)
+    }
+
+    /// Take the value out, leaving no value in its place.
+    pub fn take(&mut self) -> Option<T> {
+        core::mem::replace(self, Self::NONE).get()
+    }
+
+    /// Replace the value, returning the old value.
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        core::mem::replace(self, Self::some(value)).get()
+    }
+}
+
+impl<T: Niche> Default for OptionRanged<T> {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl<T: Niche> From<T> for OptionRanged<T> {
+    fn from(value: T) -> Self {
+        Self::some(value)
+    }
+}
+
+impl<T: Niche> From<Option<T>> for OptionRanged<T> {
+    fn from(option: Option<T>) -> Self {
+        option.map_or(Self::NONE, Self::some)
+    }
+}
+
+impl<T: Niche> From<OptionRanged<T>> for Option<T> {
+    fn from(option: OptionRanged<T>) -> Self {
+        option.get()
+    }
+}
+
+impl<T: Niche> PartialOrd for OptionRanged<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Niche> Ord for OptionRanged<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl<T: Niche> fmt::Debug for OptionRanged<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+macro_rules! impl_niche {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Niche for $type<MIN, MAX> {
+            const NICHE: $p = if MAX < <$p>::MAX {
+                <$p>::MAX
+            } else if MIN > <$p>::MIN {
+                <$p>::MIN
+            } else {
+                panic!("range must leave an unused value")
+            };
+        }
+    };
+}
+
+macro_rules! impl_niche_nonzero {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Niche for $type<MIN, MAX> {
+            const NICHE: $p = 0;
+        }
+    };
+}
+
+impl_niche!(RangedI8, i8);
+impl_niche!(RangedI16, i16);
+impl_niche!(RangedI32, i32);
+impl_niche!(RangedI64, i64);
+impl_niche!(RangedI128, i128);
+impl_niche!(RangedIsize, isize);
+impl_niche!(RangedU8, u8);
+impl_niche!(RangedU16, u16);
+impl_niche!(RangedU32, u32);
+impl_niche!(RangedU64, u64);
+impl_niche!(RangedU128, u128);
+impl_niche!(RangedUsize, usize);
+
+impl_niche_nonzero!(RangedNonZeroI8, i8);
+impl_niche_nonzero!(RangedNonZeroI16, i16);
+impl_niche_nonzero!(RangedNonZeroI32, i32);
+impl_niche_nonzero!(RangedNonZeroI64, i64);
+impl_niche_nonzero!(RangedNonZeroI128, i128);
+impl_niche_nonzero!(RangedNonZeroIsize, isize);
+impl_niche_nonzero!(RangedNonZeroU8, u8);
+impl_niche_nonzero!(RangedNonZeroU16, u16);
+impl_niche_nonzero!(RangedNonZeroU32, u32);
+impl_niche_nonzero!(RangedNonZeroU64, u64);
+impl_niche_nonzero!(RangedNonZeroU128, u128);
+impl_niche_nonzero!(RangedNonZeroUsize, usize);
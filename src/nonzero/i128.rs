@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedI128, RangedU32, Result};
 
 /// [`i128`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroI128<const MIN: i128, const MAX: i128>(
     pub(crate) NonZero<i128>,
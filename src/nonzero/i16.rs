@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedI16, RangedU32, Result};
 
 /// [`i16`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroI16<const MIN: i16, const MAX: i16>(
     pub(crate) NonZero<i16>,
@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedI32, RangedU32, Result};
 
 /// [`i32`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroI32<const MIN: i32, const MAX: i32>(
     pub(crate) NonZero<i32>,
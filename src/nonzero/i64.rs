@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedI64, RangedU32, Result};
 
 /// [`i64`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroI64<const MIN: i64, const MAX: i64>(
     pub(crate) NonZero<i64>,
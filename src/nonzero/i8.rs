@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedI8, RangedU32, Result};
 
 /// [`i8`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroI8<const MIN: i8, const MAX: i8>(
     pub(crate) NonZero<i8>,
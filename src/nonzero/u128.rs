@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedU32, RangedU128, Result};
 
 /// [`u128`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroU128<const MIN: u128, const MAX: u128>(
     pub(crate) NonZero<u128>,
@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedU16, RangedU32, Result};
 
 /// [`u16`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroU16<const MIN: u16, const MAX: u16>(
     pub(crate) NonZero<u16>,
@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedU32, Result};
 
 /// [`u32`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroU32<const MIN: u32, const MAX: u32>(
     pub(crate) NonZero<u32>,
@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedU32, RangedU64, Result};
 
 /// [`u64`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroU64<const MIN: u64, const MAX: u64>(
     pub(crate) NonZero<u64>,
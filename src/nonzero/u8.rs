@@ -6,6 +6,10 @@ use crate::{Error, Quotient, RangedU8, RangedU32, Result};
 
 /// [`u8`] not to equal zero with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedNonZeroU8<const MIN: u8, const MAX: u8>(
     pub(crate) NonZero<u8>,
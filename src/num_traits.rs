@@ -0,0 +1,172 @@
+use ::num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive,
+    NumCast, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub,
+    ToPrimitive,
+};
+
+use super::*;
+
+macro_rules! to_primitive {
+    ($($method:ident -> $out:ty),* $(,)?) => {
+        $(
+            fn $method(&self) -> Option<$out> {
+                self.get().$method()
+            }
+        )*
+    };
+}
+
+macro_rules! from_primitive {
+    ($p:ty; $($method:ident($in:ty)),* $(,)?) => {
+        $(
+            fn $method(n: $in) -> Option<Self> {
+                Self::with_primitive(<$p>::$method(n)?).ok()
+            }
+        )*
+    };
+}
+
+macro_rules! impl_num_traits {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Bounded for $type<MIN, MAX> {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> Saturating for $type<MIN, MAX> {
+            fn saturating_add(self, v: Self) -> Self {
+                RangedInt::saturating_add(self, v.get())
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                RangedInt::saturating_sub(self, v.get())
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> ToPrimitive for $type<MIN, MAX> {
+            to_primitive!(
+                to_isize -> isize,
+                to_i8 -> i8,
+                to_i16 -> i16,
+                to_i32 -> i32,
+                to_i64 -> i64,
+                to_i128 -> i128,
+                to_usize -> usize,
+                to_u8 -> u8,
+                to_u16 -> u16,
+                to_u32 -> u32,
+                to_u64 -> u64,
+                to_u128 -> u128,
+                to_f32 -> f32,
+                to_f64 -> f64,
+            );
+        }
+
+        impl<const MIN: $p, const MAX: $p> FromPrimitive for $type<MIN, MAX> {
+            from_primitive!(
+                $p;
+                from_isize(isize),
+                from_i8(i8),
+                from_i16(i16),
+                from_i32(i32),
+                from_i64(i64),
+                from_i128(i128),
+                from_usize(usize),
+                from_u8(u8),
+                from_u16(u16),
+                from_u32(u32),
+                from_u64(u64),
+                from_u128(u128),
+                from_f32(f32),
+                from_f64(f64),
+            );
+        }
+
+        impl<const MIN: $p, const MAX: $p> NumCast for $type<MIN, MAX> {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                Self::with_primitive(<$p as NumCast>::from(n)?).ok()
+            }
+        }
+    };
+}
+
+macro_rules! impl_num_traits_ops {
+    ($type:ident, $p:ty $(,)?) => {
+        impl_num_traits!($type, $p);
+
+        impl<const MIN: $p, const MAX: $p> CheckedAdd for $type<MIN, MAX> {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                RangedInt::checked_add(*self, v.get()).ok()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> CheckedSub for $type<MIN, MAX> {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                RangedInt::checked_sub(*self, v.get()).ok()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> CheckedMul for $type<MIN, MAX> {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                RangedInt::checked_mul(*self, v.get()).ok()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> CheckedDiv for $type<MIN, MAX> {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                RangedInt::checked_div(*self, v.get()).ok()?.number()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> SaturatingAdd for $type<MIN, MAX> {
+            fn saturating_add(&self, v: &Self) -> Self {
+                RangedInt::saturating_add(*self, v.get())
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> SaturatingSub for $type<MIN, MAX> {
+            fn saturating_sub(&self, v: &Self) -> Self {
+                RangedInt::saturating_sub(*self, v.get())
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> SaturatingMul for $type<MIN, MAX> {
+            fn saturating_mul(&self, v: &Self) -> Self {
+                RangedInt::saturating_mul(*self, v.get())
+            }
+        }
+    };
+}
+
+impl_num_traits_ops!(RangedU8, u8);
+impl_num_traits_ops!(RangedU16, u16);
+impl_num_traits_ops!(RangedU32, u32);
+impl_num_traits_ops!(RangedU64, u64);
+impl_num_traits_ops!(RangedU128, u128);
+impl_num_traits_ops!(RangedUsize, usize);
+
+impl_num_traits_ops!(RangedI8, i8);
+impl_num_traits_ops!(RangedI16, i16);
+impl_num_traits_ops!(RangedI32, i32);
+impl_num_traits_ops!(RangedI64, i64);
+impl_num_traits_ops!(RangedI128, i128);
+impl_num_traits_ops!(RangedIsize, isize);
+
+impl_num_traits!(RangedNonZeroU8, u8);
+impl_num_traits!(RangedNonZeroU16, u16);
+impl_num_traits!(RangedNonZeroU32, u32);
+impl_num_traits!(RangedNonZeroU64, u64);
+impl_num_traits!(RangedNonZeroU128, u128);
+impl_num_traits!(RangedNonZeroUsize, usize);
+
+impl_num_traits!(RangedNonZeroI8, i8);
+impl_num_traits!(RangedNonZeroI16, i16);
+impl_num_traits!(RangedNonZeroI32, i32);
+impl_num_traits!(RangedNonZeroI64, i64);
+impl_num_traits!(RangedNonZeroI128, i128);
+impl_num_traits!(RangedNonZeroIsize, isize);
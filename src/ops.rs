@@ -1,6 +1,6 @@
 use core::{
     num::NonZero,
-    ops::{Add, Div, Mul, Sub},
+    ops::{Add, Div, Mul, Rem, Sub},
 };
 
 use as_repr::AsRepr;
@@ -12,7 +12,9 @@ macro_rules! impl_ops {
         $type:ident,
         $p:ty,
         $nonzero:ident,
+        $with:ident,
         $ret:ident,
+        $checked:ident,
         $nan_unreachable:ident $(,)?
     ) => {
         impl<T, const MIN: $p, const MAX: $p> Add<T> for $type::<MIN, MAX>
@@ -59,6 +61,17 @@ macro_rules! impl_ops {
             }
         }
 
+        impl<T, const MIN: $p, const MAX: $p> Rem<T> for $type::<MIN, MAX>
+        where
+            T: AsRepr<NonZero<$p>>,
+        {
+            type Output = Self;
+
+            fn rem(self, other: T) -> Self {
+                self.checked_rem(other).expect("out of range")
+            }
+        }
+
         impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
             /// Add a number to `self`.
             ///
@@ -311,7 +324,7 @@ macro_rules! impl_ops {
             #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
             #[doc = concat!("let a = ", stringify!($type), "::<2, 5>::new::<3>();")]
             #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 2>::new::<2>();")]
-            #[doc = concat!("let output: ", stringify!($type), "<1, 2> = a.div_ranged_nonzero(b);")]
+            #[doc = concat!("let output: ", stringify!($type), "<1, 5> = a.div_ranged_nonzero(b);")]
             ///
             /// assert_eq!(output.get(), 1);
             /// ```
@@ -348,6 +361,253 @@ macro_rules! impl_ops {
                     Quotient::Nan => unreachable!(),
                 }
             }
+
+            /// Checked integer remainder by a non-zero number.
+            ///
+            /// The result has the same sign as `self`.  Fails if the result is
+            /// out of range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 50>::new::<50>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 50>::new::<7>();")]
+            ///
+            /// assert_eq!(a.checked_rem(b).unwrap().get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_rem(
+                self,
+                rhs: impl AsRepr<NonZero<$p>>,
+            ) -> $ret::<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                $checked(Self::$with(self.get().wrapping_rem(rhs.get())))
+            }
+
+            /// Checked Euclidean remainder by a non-zero number.
+            ///
+            /// The result is never negative.  Fails if the result is out of
+            /// range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 50>::new::<50>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 50>::new::<7>();")]
+            ///
+            /// assert_eq!(a.checked_rem_euclid(b).unwrap().get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn checked_rem_euclid(
+                self,
+                rhs: impl AsRepr<NonZero<$p>>,
+            ) -> $ret::<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                $checked(Self::$with(self.get().wrapping_rem_euclid(rhs.get())))
+            }
+
+            /// Saturating integer remainder by a non-zero number.
+            ///
+            /// The result has the same sign as `self`.  Returns [`Self::MIN`]
+            /// or [`Self::MAX`] if the result is out of range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<10, 50>::new::<50>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 50>::new::<7>();")]
+            #[doc = concat!("let c = ", stringify!($nonzero), "::<1, 50>::new::<30>();")]
+            ///
+            /// assert_eq!(a.saturating_rem(b).get(), 10);
+            /// assert_eq!(a.saturating_rem(c).get(), 20);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn saturating_rem(
+                self,
+                rhs: impl AsRepr<NonZero<$p>>,
+            ) -> Self {
+                let rhs = as_repr::as_repr(rhs);
+                let value = self.get().wrapping_rem(rhs.get());
+
+                if value < MIN {
+                    Self::MIN
+                } else if value > MAX {
+                    Self::MAX
+                } else {
+                    $type(value)
+                }
+            }
+
+            /// Calculate the remainder of `self` divided by a non-zero number.
+            ///
+            /// The output is bounded by both `self` and the largest possible
+            /// divisor magnitude, so a non-negative `self` results in
+            /// `0..=D_MAX - 1` (or `0..=MAX` if that's smaller).
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 10>::new::<10>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 9> = a.rem_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 3);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 10>::new::<10>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 10> = a.rem_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 3);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn rem_ranged<
+                const D_MIN: $p,
+                const D_MAX: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: $nonzero::<D_MIN, D_MAX>,
+            ) -> $type::<OUTPUT_MIN, OUTPUT_MAX> {
+                // `MIN` and `D_*` comparisons only needed for signed numbers
+                #[allow(unused_comparisons)]
+                const {
+                    // largest possible remainder magnitude, `|d| - 1`
+                    let d_min = if D_MIN < 0 { !D_MIN } else { D_MIN - 1 };
+                    let d_max = if D_MAX < 0 { !D_MAX } else { D_MAX - 1 };
+                    let limit = if d_min > d_max { d_min } else { d_max };
+                    let min = if MIN >= 0 {
+                        0
+                    } else if MIN < 0 - limit {
+                        0 - limit
+                    } else {
+                        MIN
+                    };
+                    let max = if MAX <= 0 {
+                        0
+                    } else if MAX > limit {
+                        limit
+                    } else {
+                        MAX
+                    };
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get().wrapping_rem(rhs.get()))
+            }
+
+            /// Calculate the Euclidean remainder of `self` divided by a
+            /// non-zero number.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 10>::new::<10>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<0, 9> = a.rem_euclid_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 3);
+            /// ```
+            ///
+            /// Does not compile:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($nonzero), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let b = ", stringify!($nonzero), "::<1, 10>::new::<10>();")]
+            #[doc = concat!("let output: ", stringify!($type), "<1, 9> = a.rem_euclid_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 3);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn rem_euclid_ranged<
+                const D_MIN: $p,
+                const D_MAX: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+                rhs: $nonzero::<D_MIN, D_MAX>,
+            ) -> $type::<OUTPUT_MIN, OUTPUT_MAX> {
+                // `MIN` and `D_*` comparisons only needed for signed numbers
+                #[allow(unused_comparisons)]
+                const {
+                    // largest possible remainder, `|d| - 1`
+                    let d_min = if D_MIN < 0 { !D_MIN } else { D_MIN - 1 };
+                    let d_max = if D_MAX < 0 { !D_MAX } else { D_MAX - 1 };
+                    let limit = if d_min > d_max { d_min } else { d_max };
+                    let max = if MIN < 0 || MAX > limit { limit } else { MAX };
+
+                    if OUTPUT_MIN != 0 {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $type(self.get().wrapping_rem_euclid(rhs.get()))
+            }
+
+            /// Calculate the remainder of `self` divided by a constant.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<0, 7>::new::<7>();")]
+            /// assert_eq!(a.rem::<8, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn rem<
+                const RHS: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type::<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { $nonzero::<RHS, RHS>::new::<RHS>() };
+
+                self.rem_ranged(rhs)
+            }
+
+            /// Calculate the Euclidean remainder of `self` divided by a
+            /// constant.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<23>();")]
+            #[doc = concat!("let output = ", stringify!($type), "::<0, 7>::new::<7>();")]
+            /// assert_eq!(a.rem_euclid::<8, _, _>(), output);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn rem_euclid<
+                const RHS: $p,
+                const OUTPUT_MIN: $p,
+                const OUTPUT_MAX: $p,
+            >(
+                self,
+            ) -> $type::<OUTPUT_MIN, OUTPUT_MAX> {
+                let rhs = const { $nonzero::<RHS, RHS>::new::<RHS>() };
+
+                self.rem_euclid_ranged(rhs)
+            }
         }
     };
 }
@@ -832,35 +1092,54 @@ impl_ops!(
     RangedI8,
     i8,
     RangedNonZeroI8,
+    with_i8,
     Result,
+    signed_checked,
     signed_nan_unreachable,
 );
 impl_ops!(
     RangedI16,
     i16,
     RangedNonZeroI16,
+    with_i16,
     Result,
+    signed_checked,
     signed_nan_unreachable,
 );
 impl_ops!(
     RangedI32,
     i32,
     RangedNonZeroI32,
+    with_i32,
     Result,
+    signed_checked,
     signed_nan_unreachable,
 );
 impl_ops!(
     RangedI64,
     i64,
     RangedNonZeroI64,
+    with_i64,
     Result,
+    signed_checked,
     signed_nan_unreachable,
 );
 impl_ops!(
     RangedI128,
     i128,
     RangedNonZeroI128,
+    with_i128,
     Result,
+    signed_checked,
+    signed_nan_unreachable,
+);
+impl_ops!(
+    RangedIsize,
+    isize,
+    RangedNonZeroIsize,
+    with_isize,
+    Result,
+    signed_checked,
     signed_nan_unreachable,
 );
 
@@ -868,35 +1147,54 @@ impl_ops!(
     RangedU8,
     u8,
     RangedNonZeroU8,
+    with_u8,
     Option,
+    unsigned_checked,
     unsigned_nan_unreachable,
 );
 impl_ops!(
     RangedU16,
     u16,
     RangedNonZeroU16,
+    with_u16,
     Option,
+    unsigned_checked,
     unsigned_nan_unreachable,
 );
 impl_ops!(
     RangedU32,
     u32,
     RangedNonZeroU32,
+    with_u32,
     Option,
+    unsigned_checked,
     unsigned_nan_unreachable,
 );
 impl_ops!(
     RangedU64,
     u64,
     RangedNonZeroU64,
+    with_u64,
     Option,
+    unsigned_checked,
     unsigned_nan_unreachable,
 );
 impl_ops!(
     RangedU128,
     u128,
     RangedNonZeroU128,
+    with_u128,
+    Option,
+    unsigned_checked,
+    unsigned_nan_unreachable,
+);
+impl_ops!(
+    RangedUsize,
+    usize,
+    RangedNonZeroUsize,
+    with_usize,
     Option,
+    unsigned_checked,
     unsigned_nan_unreachable,
 );
 
@@ -905,18 +1203,38 @@ impl_ops_nonzero_signed!(RangedNonZeroI16, i16);
 impl_ops_nonzero_signed!(RangedNonZeroI32, i32);
 impl_ops_nonzero_signed!(RangedNonZeroI64, i64);
 impl_ops_nonzero_signed!(RangedNonZeroI128, i128);
+impl_ops_nonzero_signed!(RangedNonZeroIsize, isize);
 
 impl_ops_nonzero_unsigned!(RangedNonZeroU8, u8);
 impl_ops_nonzero_unsigned!(RangedNonZeroU16, u16);
 impl_ops_nonzero_unsigned!(RangedNonZeroU32, u32);
 impl_ops_nonzero_unsigned!(RangedNonZeroU64, u64);
 impl_ops_nonzero_unsigned!(RangedNonZeroU128, u128);
+impl_ops_nonzero_unsigned!(RangedNonZeroUsize, usize);
 
 impl_ops_unsigned!(RangedU8, u8, RangedNonZeroU8, with_u8);
 impl_ops_unsigned!(RangedU16, u16, RangedNonZeroU16, with_u16);
 impl_ops_unsigned!(RangedU32, u32, RangedNonZeroU32, with_u32);
 impl_ops_unsigned!(RangedU64, u64, RangedNonZeroU64, with_u64);
 impl_ops_unsigned!(RangedU128, u128, RangedNonZeroU128, with_u128);
+impl_ops_unsigned!(RangedUsize, usize, RangedNonZeroUsize, with_usize);
+
+pub(super) const fn signed_checked<T>(result: Result<T>) -> Result<T>
+where
+    T: Copy + Clone,
+{
+    result
+}
+
+pub(super) const fn unsigned_checked<T>(result: Result<T>) -> Option<T>
+where
+    T: Copy + Clone,
+{
+    match result {
+        Ok(value) => Some(value),
+        Err(_) => None,
+    }
+}
 
 const fn signed_nan_unreachable<T>(result: Result<Quotient<T>>) -> Result<T>
 where
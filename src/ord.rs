@@ -1,10 +1,10 @@
 use crate::{
-    RangedI8, RangedI16, RangedI32, RangedI64, RangedI128, RangedU8, RangedU16,
-    RangedU32, RangedU64, RangedU128,
+    RangedI8, RangedI16, RangedI32, RangedI64, RangedI128, RangedIsize,
+    RangedU8, RangedU16, RangedU32, RangedU64, RangedU128, RangedUsize, bounds,
 };
 
 macro_rules! const_ord {
-    ($ty: ident, $p: ty) => {
+    ($ty: ident, $p: ident) => {
         impl<const MIN: $p, const MAX: $p> $ty<MIN, MAX> {
             /// Return the minimum of two ranged integers.
             ///
@@ -26,8 +26,10 @@ macro_rules! const_ord {
                 other: $ty<OTHER_MIN, OTHER_MAX>,
             ) -> $ty<OUTPUT_MIN, OUTPUT_MAX> {
                 const {
-                    let min = if MIN < OTHER_MIN { MIN } else { OTHER_MIN };
-                    let max = if MAX < OTHER_MAX { MAX } else { OTHER_MAX };
+                    let min =
+                        bounds::$p::min_min(MIN, MAX, OTHER_MIN, OTHER_MAX);
+                    let max =
+                        bounds::$p::min_max(MIN, MAX, OTHER_MIN, OTHER_MAX);
 
                     if OUTPUT_MIN != min {
                         panic!("Mimatched minimum")
@@ -65,8 +67,10 @@ macro_rules! const_ord {
                 other: $ty<OTHER_MIN, OTHER_MAX>,
             ) -> $ty<OUTPUT_MIN, OUTPUT_MAX> {
                 const {
-                    let min = if MIN > OTHER_MIN { MIN } else { OTHER_MIN };
-                    let max = if MAX > OTHER_MAX { MAX } else { OTHER_MAX };
+                    let min =
+                        bounds::$p::max_min(MIN, MAX, OTHER_MIN, OTHER_MAX);
+                    let max =
+                        bounds::$p::max_max(MIN, MAX, OTHER_MIN, OTHER_MAX);
 
                     if OUTPUT_MIN != min {
                         panic!("Mimatched minimum")
@@ -112,8 +116,12 @@ macro_rules! const_ord {
                 max: $ty<MAX_MIN, MAX_MAX>,
             ) -> $ty<OUTPUT_MIN, OUTPUT_MAX> {
                 const {
-                    let min = if MIN > MIN_MIN { MIN } else { MIN_MIN };
-                    let max = if MAX < MAX_MAX { MAX } else { MAX_MAX };
+                    let min = bounds::$p::clamp_min(
+                        MIN, MAX, MIN_MIN, MIN_MAX, MAX_MIN, MAX_MAX,
+                    );
+                    let max = bounds::$p::clamp_max(
+                        MIN, MAX, MIN_MIN, MIN_MAX, MAX_MIN, MAX_MAX,
+                    );
 
                     if OUTPUT_MIN != min {
                         panic!("Mimatched minimum")
@@ -145,9 +153,11 @@ const_ord!(RangedI16, i16);
 const_ord!(RangedI32, i32);
 const_ord!(RangedI64, i64);
 const_ord!(RangedI128, i128);
+const_ord!(RangedIsize, isize);
 
 const_ord!(RangedU8, u8);
 const_ord!(RangedU16, u16);
 const_ord!(RangedU32, u32);
 const_ord!(RangedU64, u64);
 const_ord!(RangedU128, u128);
+const_ord!(RangedUsize, usize);
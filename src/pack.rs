@@ -0,0 +1,407 @@
+//! Bit-packing for arbitrary width integers
+//!
+//! A [`BitWriter`] stores each ranged integer in exactly [`Pack::WIDTH`] bits,
+//! so a [`bitwise::U12`] takes up 12 bits and a [`bitwise::I3`] takes up 3
+//! bits.  A [`BitReader`] reads them back, validating that each value is in
+//! range.
+//!
+//! ```rust
+//! # use ranch::{bitwise::{I3, U12, U5}, pack::{BitOrder, BitReader, BitWriter}};
+//! let mut buffer = [0; 3];
+//! let mut writer = BitWriter::new(&mut buffer, BitOrder::MsbFirst);
+//!
+//! writer.write(U5::new::<0b10110>()).unwrap();
+//! writer.write(U12::new::<0xABC>()).unwrap();
+//! writer.write(I3::new::<-1>()).unwrap();
+//! assert_eq!(writer.position(), 20);
+//! assert_eq!(buffer, [0b1011_0101, 0b0101_1110, 0b0111_0000]);
+//!
+//! let mut reader = BitReader::new(&buffer, BitOrder::MsbFirst);
+//!
+//! assert_eq!(reader.read(), Ok(U5::new::<0b10110>()));
+//! assert_eq!(reader.read(), Ok(U12::new::<0xABC>()));
+//! assert_eq!(reader.read(), Ok(I3::new::<-1>()));
+//! ```
+
+use core::{error, fmt, result};
+
+use super::*;
+
+/// Bit-packing result
+pub type Result<T = (), E = Error> = result::Result<T, E>;
+
+/// Error packing or unpacking ranged integers
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Error {
+    /// Integer is too large to store in target integer type
+    PosOverflow,
+    /// Integer is too small to store in target integer type
+    NegOverflow,
+    /// Integer is zero
+    Zero,
+    /// Not enough bits left in the buffer
+    EndOfBuffer,
+}
+
+impl error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Zero => "zero cannot be converted to target integer type",
+            Self::PosOverflow => {
+                "integer is too large to convert to target integer type"
+            }
+            Self::NegOverflow => {
+                "integer is too small to convert to target integer type"
+            }
+            Self::EndOfBuffer => "not enough bits left in the buffer",
+        })
+    }
+}
+
+impl From<range::Error> for Error {
+    fn from(error: range::Error) -> Self {
+        match error {
+            range::Error::PosOverflow => Self::PosOverflow,
+            range::Error::NegOverflow => Self::NegOverflow,
+            range::Error::Zero => Self::Zero,
+        }
+    }
+}
+
+/// The order bits are packed in
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BitOrder {
+    /// Write the most significant bit of each integer first, filling each
+    /// byte starting from its most significant bit
+    MsbFirst,
+    /// Write the least significant bit of each integer first, filling each
+    /// byte starting from its least significant bit
+    LsbFirst,
+}
+
+impl BitOrder {
+    /// Get the mask for the bit at `position` within its byte.
+    const fn byte_mask(self, position: usize) -> u8 {
+        let index = position % 8;
+
+        match self {
+            Self::MsbFirst => 0x80 >> index,
+            Self::LsbFirst => 0x01 << index,
+        }
+    }
+
+    /// Get the shift for the `index`th packed bit of a `width` bit integer.
+    const fn shift(self, index: u32, width: u32) -> u32 {
+        match self {
+            Self::MsbFirst => width - 1 - index,
+            Self::LsbFirst => index,
+        }
+    }
+}
+
+/// A ranged integer that can be bit-packed
+///
+/// Integers that can be negative are packed in two's complement, and all other
+/// integers are packed as unsigned.  Values are not offset by the minimum, so
+/// `RangedU8<100, 127>` still takes up 7 bits.
+pub trait Pack: RangedInt {
+    /// The number of bits needed to store every value in the range.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::{I12, NonZeroU3, U12}, pack::Pack, RangedU8};
+    /// assert_eq!(U12::WIDTH, 12);
+    /// assert_eq!(I12::WIDTH, 12);
+    /// assert_eq!(NonZeroU3::WIDTH, 3);
+    /// assert_eq!(RangedU8::<0, 100>::WIDTH, 7);
+    /// ```
+    const WIDTH: u32;
+
+    /// Get the lowest [`Self::WIDTH`] bits of the two's complement
+    /// representation.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::I4, pack::Pack};
+    /// assert_eq!(I4::new::<-2>().to_bits(), 0b1110);
+    /// ```
+    fn to_bits(self) -> u128;
+
+    /// Try to create a ranged integer from the lowest [`Self::WIDTH`] bits,
+    /// ignoring any higher bits.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::I4, pack::Pack, range::Error, RangedU8};
+    /// assert_eq!(I4::from_bits(0b1110), Ok(I4::new::<-2>()));
+    /// assert_eq!(
+    ///     RangedU8::<0, 100>::from_bits(127),
+    ///     Err(Error::PosOverflow),
+    /// );
+    /// ```
+    fn from_bits(bits: u128) -> range::Result<Self>;
+}
+
+/// Writes bit-packed ranged integers into a byte buffer
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buffer: &'a mut [u8],
+    order: BitOrder,
+    position: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Create a new writer, starting at the beginning of `buffer`.
+    pub const fn new(buffer: &'a mut [u8], order: BitOrder) -> Self {
+        Self {
+            buffer,
+            order,
+            position: 0,
+        }
+    }
+
+    /// Write exactly [`Pack::WIDTH`] bits for `value`.
+    ///
+    /// Returns [`Error::EndOfBuffer`] without writing anything if there isn't
+    /// enough space left in the buffer.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::U3, pack::{BitOrder, BitWriter, Error}};
+    /// let mut buffer = [0xFF];
+    /// let mut writer = BitWriter::new(&mut buffer, BitOrder::LsbFirst);
+    ///
+    /// writer.write(U3::new::<0b001>()).unwrap();
+    /// writer.write(U3::new::<0b010>()).unwrap();
+    /// assert_eq!(writer.write(U3::new::<0b100>()), Err(Error::EndOfBuffer));
+    /// assert_eq!(buffer, [0b1101_0001]);
+    /// ```
+    pub fn write<T: Pack>(&mut self, value: T) -> Result {
+        let width = T::WIDTH;
+
+        if remaining(self.buffer.len(), self.position) < width as usize {
+            return Err(Error::EndOfBuffer);
+        }
+
+        let bits = value.to_bits();
+
+        for index in 0..width {
+            let position = self.position + index as usize;
+            let mask = self.order.byte_mask(position);
+            let byte = &mut self.buffer[position / 8];
+
+            if (bits >> self.order.shift(index, width)) & 1 == 0 {
+                *byte &= !mask;
+            } else {
+                *byte |= mask;
+            }
+        }
+
+        self.position += width as usize;
+
+        Ok(())
+    }
+
+    /// Return the number of bits written so far.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Finish writing, returning the bytes that have been written to.
+    ///
+    /// Unused trailing bits in the last byte are left unchanged.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::U4, pack::{BitOrder, BitWriter}};
+    /// let mut buffer = [0; 4];
+    /// let mut writer = BitWriter::new(&mut buffer, BitOrder::MsbFirst);
+    ///
+    /// writer.write(U4::new::<0xA>()).unwrap();
+    /// writer.write(U4::new::<0xB>()).unwrap();
+    /// writer.write(U4::new::<0xC>()).unwrap();
+    /// assert_eq!(writer.finish(), [0xAB, 0xC0]);
+    /// ```
+    pub fn finish(self) -> &'a mut [u8] {
+        &mut self.buffer[..self.position.div_ceil(8)]
+    }
+}
+
+/// Reads bit-packed ranged integers from a byte buffer
+#[derive(Debug)]
+pub struct BitReader<'a> {
+    buffer: &'a [u8],
+    order: BitOrder,
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Create a new reader, starting at the beginning of `buffer`.
+    pub const fn new(buffer: &'a [u8], order: BitOrder) -> Self {
+        Self {
+            buffer,
+            order,
+            position: 0,
+        }
+    }
+
+    /// Read exactly [`Pack::WIDTH`] bits, and validate that they are in range.
+    ///
+    /// On error, the reader's position is left unchanged.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::NonZeroU4, pack::{BitOrder, BitReader, Error}};
+    /// let mut reader = BitReader::new(&[0x0A], BitOrder::MsbFirst);
+    ///
+    /// assert_eq!(reader.read::<NonZeroU4>(), Err(Error::Zero));
+    /// assert_eq!(reader.read::<NonZeroU4>(), Err(Error::Zero));
+    /// ```
+    pub fn read<T: Pack>(&mut self) -> Result<T> {
+        let width = T::WIDTH;
+
+        if remaining(self.buffer.len(), self.position) < width as usize {
+            return Err(Error::EndOfBuffer);
+        }
+
+        let mut bits = 0;
+
+        for index in 0..width {
+            let position = self.position + index as usize;
+            let mask = self.order.byte_mask(position);
+
+            if self.buffer[position / 8] & mask != 0 {
+                bits |= 1 << self.order.shift(index, width);
+            }
+        }
+
+        let value = T::from_bits(bits)?;
+
+        self.position += width as usize;
+
+        Ok(value)
+    }
+
+    /// Skip over `bits` bits.
+    ///
+    /// ```rust
+    /// # use ranch::{bitwise::NonZeroU4, pack::{BitOrder, BitReader, Error}};
+    /// let mut reader = BitReader::new(&[0x0A], BitOrder::MsbFirst);
+    ///
+    /// reader.skip(4).unwrap();
+    /// assert_eq!(reader.read(), Ok(NonZeroU4::new::<0xA>()));
+    /// assert_eq!(reader.skip(1), Err(Error::EndOfBuffer));
+    /// ```
+    pub fn skip(&mut self, bits: usize) -> Result {
+        if remaining(self.buffer.len(), self.position) < bits {
+            return Err(Error::EndOfBuffer);
+        }
+
+        self.position += bits;
+
+        Ok(())
+    }
+
+    /// Return the number of bits read so far.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the number of bits left to read.
+    pub const fn remaining(&self) -> usize {
+        remaining(self.buffer.len(), self.position)
+    }
+}
+
+/// Get the number of bits left in a buffer of `len` bytes.
+const fn remaining(len: usize, position: usize) -> usize {
+    len.saturating_mul(8).saturating_sub(position)
+}
+
+/// Get a mask with the lowest `width` bits set.
+pub(crate) const fn mask(width: u32) -> u128 {
+    match u128::MAX.checked_shr(u128::BITS - width) {
+        Some(mask) => mask,
+        None => 0,
+    }
+}
+
+macro_rules! impl_pack_unsigned {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Pack for $type<MIN, MAX> {
+            const WIDTH: u32 = <$p>::BITS - MAX.leading_zeros();
+
+            // cast is only trivial for `u128`
+            #[allow(trivial_numeric_casts)]
+            fn to_bits(self) -> u128 {
+                self.get() as u128 & mask(Self::WIDTH)
+            }
+
+            fn from_bits(bits: u128) -> range::Result<Self> {
+                <$p>::try_from(bits & mask(Self::WIDTH))
+                    .map_err(|_| range::Error::PosOverflow)
+                    .and_then(Self::with_primitive)
+            }
+        }
+    };
+}
+
+macro_rules! impl_pack_signed {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Pack for $type<MIN, MAX> {
+            const WIDTH: u32 = {
+                let high = if MAX < 0 {
+                    0
+                } else {
+                    <$p>::BITS - MAX.leading_zeros()
+                };
+
+                if MIN >= 0 {
+                    high
+                } else {
+                    let low = <$p>::BITS - MIN.leading_ones();
+
+                    if high > low { high + 1 } else { low + 1 }
+                }
+            };
+
+            fn to_bits(self) -> u128 {
+                self.get() as u128 & mask(Self::WIDTH)
+            }
+
+            fn from_bits(bits: u128) -> range::Result<Self> {
+                let bits = bits & mask(Self::WIDTH);
+                let sign = (bits >> Self::WIDTH.saturating_sub(1)) & 1;
+                let bits = if MIN < 0 && sign == 1 {
+                    bits | !mask(Self::WIDTH)
+                } else {
+                    bits
+                };
+
+                Self::with_primitive(bits as $p)
+            }
+        }
+    };
+}
+
+impl_pack_unsigned!(RangedU8, u8);
+impl_pack_unsigned!(RangedU16, u16);
+impl_pack_unsigned!(RangedU32, u32);
+impl_pack_unsigned!(RangedU64, u64);
+impl_pack_unsigned!(RangedU128, u128);
+impl_pack_unsigned!(RangedUsize, usize);
+impl_pack_signed!(RangedI8, i8);
+impl_pack_signed!(RangedI16, i16);
+impl_pack_signed!(RangedI32, i32);
+impl_pack_signed!(RangedI64, i64);
+impl_pack_signed!(RangedI128, i128);
+impl_pack_signed!(RangedIsize, isize);
+
+impl_pack_unsigned!(RangedNonZeroU8, u8);
+impl_pack_unsigned!(RangedNonZeroU16, u16);
+impl_pack_unsigned!(RangedNonZeroU32, u32);
+impl_pack_unsigned!(RangedNonZeroU64, u64);
+impl_pack_unsigned!(RangedNonZeroU128, u128);
+impl_pack_unsigned!(RangedNonZeroUsize, usize);
+impl_pack_signed!(RangedNonZeroI8, i8);
+impl_pack_signed!(RangedNonZeroI16, i16);
+impl_pack_signed!(RangedNonZeroI32, i32);
+impl_pack_signed!(RangedNonZeroI64, i64);
+impl_pack_signed!(RangedNonZeroI128, i128);
+impl_pack_signed!(RangedNonZeroIsize, isize);
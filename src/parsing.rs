@@ -69,9 +69,11 @@ parse_nonzero!(RangedNonZeroI16, i16);
 parse_nonzero!(RangedNonZeroI32, i32);
 parse_nonzero!(RangedNonZeroI64, i64);
 parse_nonzero!(RangedNonZeroI128, i128);
+parse_nonzero!(RangedNonZeroIsize, isize);
 
 parse_nonzero!(RangedNonZeroU8, u8);
 parse_nonzero!(RangedNonZeroU16, u16);
 parse_nonzero!(RangedNonZeroU32, u32);
 parse_nonzero!(RangedNonZeroU64, u64);
 parse_nonzero!(RangedNonZeroU128, u128);
+parse_nonzero!(RangedNonZeroUsize, usize);
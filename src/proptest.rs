@@ -0,0 +1,76 @@
+use core::ops::RangeInclusive;
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, Strategy},
+};
+
+use super::*;
+
+macro_rules! impl_proptest {
+    ($type:ident, $p:ty, $u:ty, $add_unsigned:ident $(,)?) => {
+        impl_proptest!(@impl $type, $p, $u, $add_unsigned, false);
+    };
+    (@impl $type:ident, $p:ty, $u:ty, $add_unsigned:ident, $nonzero:literal) => {
+        impl<const MIN: $p, const MAX: $p> Arbitrary for $type<MIN, MAX> {
+            type Parameters = ();
+            type Strategy = Map<RangeInclusive<$u>, fn($u) -> Self>;
+
+            // comparisons only needed for signed numbers
+            #[allow(unused_comparisons)]
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                let skip_zero = $nonzero && MIN < 0 && MAX > 0;
+                // generate an offset from `MIN`, so values shrink toward `MIN`
+                let span = MAX.abs_diff(MIN) - <$u>::from(skip_zero);
+                let from_offset: fn($u) -> Self = |offset| {
+                    let value = MIN.$add_unsigned(offset);
+                    let skip_zero = $nonzero && MIN < 0 && MAX > 0;
+                    let value = if skip_zero && value >= 0 {
+                        value + 1
+                    } else {
+                        value
+                    };
+
+                    // saturate if there's a bug and a value out of range
+                    Self::with_primitive(value).unwrap_or(Self::MIN)
+                };
+
+                (0..=span).prop_map(from_offset)
+            }
+        }
+    };
+}
+
+macro_rules! impl_proptest_nonzero {
+    ($type:ident, $p:ty, $u:ty, $add_unsigned:ident $(,)?) => {
+        impl_proptest!(@impl $type, $p, $u, $add_unsigned, true);
+    };
+}
+
+impl_proptest!(RangedU8, u8, u8, wrapping_add);
+impl_proptest!(RangedU16, u16, u16, wrapping_add);
+impl_proptest!(RangedU32, u32, u32, wrapping_add);
+impl_proptest!(RangedU64, u64, u64, wrapping_add);
+impl_proptest!(RangedU128, u128, u128, wrapping_add);
+impl_proptest!(RangedUsize, usize, usize, wrapping_add);
+
+impl_proptest!(RangedI8, i8, u8, wrapping_add_unsigned);
+impl_proptest!(RangedI16, i16, u16, wrapping_add_unsigned);
+impl_proptest!(RangedI32, i32, u32, wrapping_add_unsigned);
+impl_proptest!(RangedI64, i64, u64, wrapping_add_unsigned);
+impl_proptest!(RangedI128, i128, u128, wrapping_add_unsigned);
+impl_proptest!(RangedIsize, isize, usize, wrapping_add_unsigned);
+
+impl_proptest_nonzero!(RangedNonZeroU8, u8, u8, wrapping_add);
+impl_proptest_nonzero!(RangedNonZeroU16, u16, u16, wrapping_add);
+impl_proptest_nonzero!(RangedNonZeroU32, u32, u32, wrapping_add);
+impl_proptest_nonzero!(RangedNonZeroU64, u64, u64, wrapping_add);
+impl_proptest_nonzero!(RangedNonZeroU128, u128, u128, wrapping_add);
+impl_proptest_nonzero!(RangedNonZeroUsize, usize, usize, wrapping_add);
+
+impl_proptest_nonzero!(RangedNonZeroI8, i8, u8, wrapping_add_unsigned);
+impl_proptest_nonzero!(RangedNonZeroI16, i16, u16, wrapping_add_unsigned);
+impl_proptest_nonzero!(RangedNonZeroI32, i32, u32, wrapping_add_unsigned);
+impl_proptest_nonzero!(RangedNonZeroI64, i64, u64, wrapping_add_unsigned);
+impl_proptest_nonzero!(RangedNonZeroI128, i128, u128, wrapping_add_unsigned);
+impl_proptest_nonzero!(RangedNonZeroIsize, isize, usize, wrapping_add_unsigned);
@@ -0,0 +1,179 @@
+use core::{fmt, marker::PhantomData};
+
+use ::rand::{
+    Rng,
+    distr::{
+        Distribution, StandardUniform,
+        uniform::{Error, SampleBorrow, SampleUniform, UniformSampler},
+    },
+};
+
+use super::*;
+
+mod sealed {
+    use super::*;
+
+    pub trait Sample: RangedInt {
+        /// Sampler for the primitive integer, which `isize` has to borrow
+        type Sampler: Copy + fmt::Debug + Eq;
+    }
+}
+
+/// Sampler for uniformly distributed ranged integers
+///
+/// This is the [`SampleUniform::Sampler`] for every ranged integer type,
+/// allowing ranged integers to be used as the endpoints of
+/// [`Rng::random_range()`].  Values are generated without modulo bias, and
+/// zero is never generated for `RangedNonZero*` types.
+///
+/// ```rust
+/// # use rand::{Rng, SeedableRng, rngs::SmallRng};
+/// # use ranch::{RangedNonZeroI8, RangedU8};
+/// type Offset = RangedNonZeroI8<-8, 8>;
+///
+/// let mut rng = SmallRng::seed_from_u64(0);
+/// let percent: RangedU8<0, 100> = rng.random();
+/// let offset = rng.random_range(Offset::new::<-2>()..=Offset::new::<2>());
+///
+/// assert!(percent.get() <= 100);
+/// assert!((-2..=2).contains(&offset.get()));
+/// assert_ne!(offset.get(), 0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UniformRanged<T: sealed::Sample> {
+    sampler: T::Sampler,
+    skip_zero: bool,
+    ranged: PhantomData<T>,
+}
+
+macro_rules! impl_rand {
+    ($type:ident, $p:ty $(,)?) => {
+        impl_rand!(@impl $type, $p, $p, false);
+    };
+    ($type:ident, $p:ty, $sample:ty $(,)?) => {
+        impl_rand!(@impl $type, $p, $sample, false);
+    };
+    (@impl $type:ident, $p:ty, $sample:ty, $nonzero:literal) => {
+        impl<const MIN: $p, const MAX: $p> sealed::Sample for $type<MIN, MAX> {
+            type Sampler = <$sample as SampleUniform>::Sampler;
+        }
+
+        impl<const MIN: $p, const MAX: $p> Distribution<$type<MIN, MAX>>
+            for StandardUniform
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type<MIN, MAX> {
+                UniformRanged::<$type<MIN, MAX>>::new_inclusive(
+                    $type::MIN,
+                    $type::MAX,
+                )
+                .map_or($type::MIN, |sampler| sampler.sample(rng))
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> SampleUniform for $type<MIN, MAX> {
+            type Sampler = UniformRanged<Self>;
+        }
+
+        impl<const MIN: $p, const MAX: $p> UniformRanged<$type<MIN, MAX>> {
+            /// Create a sampler for the inclusive range `low..=high`.
+            // casts are only non-trivial for `isize`
+            #[allow(trivial_numeric_casts)]
+            fn with_bounds(low: $p, high: $p) -> Result<Self, Error> {
+                // `low` comparison only needed for signed numbers
+                #[allow(unused_comparisons)]
+                let skip_zero = $nonzero && low < 0 && high >= 0;
+                // sample one less value, and shift the non-negative ones up
+                let high = if skip_zero { high - 1 } else { high };
+
+                Ok(Self {
+                    sampler: UniformSampler::new_inclusive(
+                        low as $sample,
+                        high as $sample,
+                    )?,
+                    skip_zero,
+                    ranged: PhantomData,
+                })
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> UniformSampler
+            for UniformRanged<$type<MIN, MAX>>
+        {
+            type X = $type<MIN, MAX>;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow().get(), high.borrow().get());
+
+                if low >= high {
+                    return Err(Error::EmptyRange);
+                }
+
+                Self::with_bounds(low, high - 1)
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self::with_bounds(low.borrow().get(), high.borrow().get())
+            }
+
+            // comparison only needed for signed numbers, and cast is only
+            // non-trivial for `isize`
+            #[allow(unused_comparisons, trivial_numeric_casts)]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                let value = self.sampler.sample(rng) as $p;
+                let value = if self.skip_zero && value >= 0 {
+                    value + 1
+                } else {
+                    value
+                };
+
+                // saturate if there's a bug and a value out of range
+                $type::with_primitive(value).unwrap_or($type::MIN)
+            }
+        }
+    };
+}
+
+macro_rules! impl_rand_nonzero {
+    ($type:ident, $p:ty $(,)?) => {
+        impl_rand!(@impl $type, $p, $p, true);
+    };
+    ($type:ident, $p:ty, $sample:ty $(,)?) => {
+        impl_rand!(@impl $type, $p, $sample, true);
+    };
+}
+
+impl_rand!(RangedU8, u8);
+impl_rand!(RangedU16, u16);
+impl_rand!(RangedU32, u32);
+impl_rand!(RangedU64, u64);
+impl_rand!(RangedU128, u128);
+impl_rand!(RangedUsize, usize);
+
+impl_rand!(RangedI8, i8);
+impl_rand!(RangedI16, i16);
+impl_rand!(RangedI32, i32);
+impl_rand!(RangedI64, i64);
+impl_rand!(RangedI128, i128);
+impl_rand!(RangedIsize, isize, i64);
+
+impl_rand_nonzero!(RangedNonZeroU8, u8);
+impl_rand_nonzero!(RangedNonZeroU16, u16);
+impl_rand_nonzero!(RangedNonZeroU32, u32);
+impl_rand_nonzero!(RangedNonZeroU64, u64);
+impl_rand_nonzero!(RangedNonZeroU128, u128);
+impl_rand_nonzero!(RangedNonZeroUsize, usize);
+
+impl_rand_nonzero!(RangedNonZeroI8, i8);
+impl_rand_nonzero!(RangedNonZeroI16, i16);
+impl_rand_nonzero!(RangedNonZeroI32, i32);
+impl_rand_nonzero!(RangedNonZeroI64, i64);
+impl_rand_nonzero!(RangedNonZeroI128, i128);
+impl_rand_nonzero!(RangedNonZeroIsize, isize, i64);
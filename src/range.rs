@@ -2,7 +2,7 @@
 //!
 //! Some convenience utilities for type ranges.
 
-use core::{error, fmt, num::NonZero, ops, result};
+use core::{error, fmt, iter::FusedIterator, num::NonZero, ops, result};
 
 use super::*;
 
@@ -116,6 +116,161 @@ where
     }
 }
 
+/// Iterator over the values of a ranged integer type, in order
+///
+/// Created with `all()` on ranged integer types, or with [`Iter::new()`] to
+/// iterate over a sub-range.
+///
+/// ```rust
+/// # use ranch::{range::Iter, RangedNonZeroI8, RangedU8};
+/// type Day = RangedU8<1, 31>;
+///
+/// assert_eq!(Day::all().len(), 31);
+/// assert_eq!(Day::all().next_back(), Some(Day::new::<31>()));
+///
+/// let weekend = Iter::new(Day::new::<6>(), Day::new::<7>());
+///
+/// assert!(weekend.map(Day::get).eq([6, 7]));
+/// assert!(
+///     RangedNonZeroI8::<-2, 2>::all()
+///         .map(RangedNonZeroI8::get)
+///         .eq([-2, -1, 1, 2])
+/// );
+/// ```
+///
+/// Like [`RangeInclusive`](ops::RangeInclusive), it's only an
+/// [`ExactSizeIterator`] when every count of values fits in a [`usize`], for
+/// ranged integers of up to 32 bits (16 bits on 32-bit targets).
+///
+/// ```rust,compile_fail
+/// # use ranch::RangedU64;
+/// let _ = RangedU64::<0, 9>::all().len();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Iter<T> {
+    front: T,
+    back: T,
+    exhausted: bool,
+}
+
+impl<T> Iter<T>
+where
+    T: RangedInt,
+{
+    /// Create an iterator from `start` to `end` (inclusive).
+    ///
+    /// The iterator is empty if `start > end`.
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            front: start,
+            back: end,
+            exhausted: start > end,
+        }
+    }
+
+    /// Create an iterator over every value of `T`.
+    pub(crate) const fn all() -> Self {
+        Self {
+            front: T::MIN,
+            back: T::MAX,
+            exhausted: false,
+        }
+    }
+}
+
+impl<T> From<ops::RangeInclusive<T>> for Iter<T>
+where
+    T: RangedInt,
+{
+    fn from(range: ops::RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self::new(start, end)
+    }
+}
+
+impl<T> Iterator for Iter<T>
+where
+    T: RangedInt,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = self.front;
+
+        match value.step_up() {
+            Some(next) if value != self.back => self.front = next,
+            _ => self.exhausted = true,
+        }
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            return (0, Some(0));
+        }
+
+        match self.front.count_to(self.back) {
+            Some(count) => (count, Some(count)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<T>
+where
+    T: RangedInt,
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = self.back;
+
+        match value.step_down() {
+            Some(next) if value != self.front => self.back = next,
+            _ => self.exhausted = true,
+        }
+
+        Some(value)
+    }
+}
+
+impl<T> FusedIterator for Iter<T> where T: RangedInt {}
+
+macro_rules! impl_exact_size {
+    ($r:ident, $p:ty) => {
+        impl<const MIN: $p, const MAX: $p> ExactSizeIterator
+            for Iter<$r<MIN, MAX>>
+        {
+        }
+    };
+}
+
+impl_exact_size!(RangedU8, u8);
+impl_exact_size!(RangedU16, u16);
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(RangedU32, u32);
+impl_exact_size!(RangedI8, i8);
+impl_exact_size!(RangedI16, i16);
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(RangedI32, i32);
+
+impl_exact_size!(RangedNonZeroU8, u8);
+impl_exact_size!(RangedNonZeroU16, u16);
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(RangedNonZeroU32, u32);
+impl_exact_size!(RangedNonZeroI8, i8);
+impl_exact_size!(RangedNonZeroI16, i16);
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(RangedNonZeroI32, i32);
+
 macro_rules! primitive_impl_range {
     ($p:ty) => {
         impl Range for $p {
@@ -146,31 +301,37 @@ ranged_impl_range!(RangedU16, u16);
 ranged_impl_range!(RangedU32, u32);
 ranged_impl_range!(RangedU64, u64);
 ranged_impl_range!(RangedU128, u128);
+ranged_impl_range!(RangedUsize, usize);
 ranged_impl_range!(RangedI8, i8);
 ranged_impl_range!(RangedI16, i16);
 ranged_impl_range!(RangedI32, i32);
 ranged_impl_range!(RangedI64, i64);
 ranged_impl_range!(RangedI128, i128);
+ranged_impl_range!(RangedIsize, isize);
 
 primitive_impl_range!(u8);
 primitive_impl_range!(u16);
 primitive_impl_range!(u32);
 primitive_impl_range!(u64);
 primitive_impl_range!(u128);
+primitive_impl_range!(usize);
 primitive_impl_range!(i8);
 primitive_impl_range!(i16);
 primitive_impl_range!(i32);
 primitive_impl_range!(i64);
 primitive_impl_range!(i128);
+primitive_impl_range!(isize);
 
 ranged_impl_range!(RangedNonZeroU8, u8);
 ranged_impl_range!(RangedNonZeroU16, u16);
 ranged_impl_range!(RangedNonZeroU32, u32);
 ranged_impl_range!(RangedNonZeroU64, u64);
 ranged_impl_range!(RangedNonZeroU128, u128);
+ranged_impl_range!(RangedNonZeroUsize, usize);
 
 primitive_impl_range!(NonZero<u8>);
 primitive_impl_range!(NonZero<u16>);
 primitive_impl_range!(NonZero<u32>);
 primitive_impl_range!(NonZero<u64>);
 primitive_impl_range!(NonZero<u128>);
+primitive_impl_range!(NonZero<usize>);
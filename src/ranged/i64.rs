@@ -1,9 +1,15 @@
 use as_repr::AsRepr;
 
-use crate::{Error, ParsingError, ParsingResult, Quotient, RangedU32, Result};
+use crate::{
+    Error, ParsingError, ParsingResult, Quotient, RangedU32, Result, bounds,
+};
 
 /// [`i64`] with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedI64<const MIN: i64, const MAX: i64>(pub(crate) i64);
 
@@ -150,13 +156,11 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
     pub const fn checked_add(self, other: impl AsRepr<i64>) -> Result<Self> {
         let other = as_repr::as_repr(other);
         let Some(value) = self.get().checked_add(other) else {
-            return Err(
-                if self.get().saturating_add(other) == Self::MAX.get() {
-                    Error::PosOverflow
-                } else {
-                    Error::NegOverflow
-                },
-            );
+            return Err(if other.is_negative() {
+                Error::NegOverflow
+            } else {
+                Error::PosOverflow
+            });
         };
 
         Self::with_i64(value)
@@ -530,11 +534,14 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
         rhs: RangedI64<RHS_MIN, RHS_MAX>,
     ) -> RangedI64<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN + RHS_MIN != OUTPUT_MIN {
+            let min = bounds::i64::add_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::i64::add_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX + RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -575,11 +582,14 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
         rhs: RangedI64<RHS_MIN, RHS_MAX>,
     ) -> RangedI64<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN - RHS_MAX != OUTPUT_MIN {
+            let min = bounds::i64::sub_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::i64::sub_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX - RHS_MIN != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -620,10 +630,8 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
         rhs: RangedI64<RHS_MIN, RHS_MAX>,
     ) -> RangedI64<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            let (min_min, min_max) = (MIN * RHS_MIN, MIN * RHS_MAX);
-            let min = if min_min < min_max { min_min } else { min_max };
-            let (max_min, max_max) = (MAX * RHS_MIN, MAX * RHS_MAX);
-            let max = if max_min > max_max { max_min } else { max_max };
+            let min = bounds::i64::mul_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::i64::mul_max(MIN, MAX, RHS_MIN, RHS_MAX);
 
             if min != OUTPUT_MIN {
                 panic!("Min mismatch");
@@ -643,11 +651,24 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
     /// # use ranch::RangedI64;
     /// let a = RangedI64::<2, 5>::new::<3>();
     /// let b = RangedI64::<1, 2>::new::<2>();
-    /// let output: RangedI64::<1, 2> = a.div_ranged(b).number().unwrap();
+    /// let output: RangedI64::<1, 5> = a.div_ranged(b).number().unwrap();
     ///
     /// assert_eq!(output.get(), 1);
     /// ```
     ///
+    /// Divisor ranges may contain zero, dividing by which is
+    /// [`Quotient::Nan`]:
+    ///
+    /// ```rust
+    /// # use ranch::RangedI64;
+    /// let a = RangedI64::<-6, 6>::new::<6>();
+    /// let b = RangedI64::<-2, 3>::new::<-2>();
+    /// let output: RangedI64::<-6, 6> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), -3);
+    /// assert!(a.div_ranged::<-2, 3, -6, 6>(RangedI64::new::<0>()).is_nan());
+    /// ```
+    ///
     /// Does not compile:
     //
     /// ```compile_fail
@@ -670,14 +691,8 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
         rhs: RangedI64<RHS_MIN, RHS_MAX>,
     ) -> Quotient<RangedI64<OUTPUT_MIN, OUTPUT_MAX>> {
         const {
-            let (min_min, min_max) = (MIN / RHS_MIN, MIN / RHS_MAX);
-            let (max_min, max_max) = (MAX / RHS_MIN, MAX / RHS_MAX);
-            let min = if min_min < min_max { min_min } else { min_max };
-            let min = if max_min < min { max_min } else { min };
-            let min = if max_max < min { max_max } else { min };
-            let max = if max_min > max_max { max_min } else { max_max };
-            let max = if min_min > min { min_min } else { max };
-            let max = if min_max > min { min_max } else { max };
+            let min = bounds::i64::div_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::i64::div_max(MIN, MAX, RHS_MIN, RHS_MAX);
 
             if min != OUTPUT_MIN {
                 panic!("Min mismatch");
@@ -728,32 +743,14 @@ impl<const MIN: i64, const MAX: i64> RangedI64<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedI64<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN.is_negative() {
-                let min = MIN.pow(RHS_MIN);
-                let max = MAX.pow(RHS_MAX);
-                let rhs_max = if RHS_MAX % 2 == 0 {
-                    RHS_MAX - 1
-                } else {
-                    RHS_MAX
-                };
-                let rhs_min = if RHS_MIN % 2 == 0 {
-                    RHS_MIN - 1
-                } else {
-                    RHS_MIN
-                };
-                let min_min = MIN.pow(rhs_min);
-                let min_max = MAX.pow(rhs_max);
-                let min = if min_min < min { min_min } else { min };
-                let min = if min_max < min { min_max } else { min };
-
-                if min != OUTPUT_MIN {
-                    panic!("Min mismatch");
-                } else if max != OUTPUT_MAX {
-                    panic!("Max mismatch");
-                }
-            } else if MIN.pow(RHS_MIN) != OUTPUT_MIN {
+            let min = bounds::i64::pow_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::i64::pow_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
-            } else if MAX.pow(RHS_MAX) != OUTPUT_MAX {
+            }
+
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
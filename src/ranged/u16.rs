@@ -1,9 +1,15 @@
 use as_repr::AsRepr;
 
-use crate::{Error, ParsingError, ParsingResult, Quotient, RangedU32, Result};
+use crate::{
+    Error, ParsingError, ParsingResult, Quotient, RangedU32, Result, bounds,
+};
 
 /// [`u16`] with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedU16<const MIN: u16, const MAX: u16>(pub(crate) u16);
 
@@ -214,7 +220,8 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
 
     /// Multiply two ranged integers together.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the product is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU16};
@@ -225,6 +232,7 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
     /// assert_eq!(b.saturating_mul(b).get(), 25);
     /// assert_eq!(a.saturating_mul(c).get(), 100);
     /// assert_eq!(c.saturating_mul(c).get(), 100);
+    /// assert_eq!(RangedU16::<1, 9>::MAX.saturating_mul(0).get(), 1);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -233,7 +241,8 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
 
         match Self::with_u16(self.get().saturating_mul(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -267,7 +276,8 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
 
     /// Raise to an integer power.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the power is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU16};
@@ -278,6 +288,7 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
     /// assert_eq!(a.saturating_pow(2).get(), 100);
     /// assert_eq!(b.saturating_pow(2).get(), 25);
     /// assert_eq!(c.saturating_pow(3).get(), 8);
+    /// assert_eq!(RangedU16::<2, 9>::MAX.saturating_pow(0).get(), 2);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -286,7 +297,8 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
 
         match Self::with_u16(self.get().saturating_pow(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -462,11 +474,14 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
         rhs: RangedU16<RHS_MIN, RHS_MAX>,
     ) -> RangedU16<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN + RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u16::add_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u16::add_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX + RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -507,11 +522,14 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
         rhs: RangedU16<RHS_MIN, RHS_MAX>,
     ) -> RangedU16<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN - RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u16::sub_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u16::sub_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX - RHS_MIN != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -552,11 +570,14 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
         rhs: RangedU16<RHS_MIN, RHS_MAX>,
     ) -> RangedU16<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN * RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u16::mul_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u16::mul_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX * RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -570,11 +591,24 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
     /// # use ranch::RangedU16;
     /// let a = RangedU16::<2, 5>::new::<3>();
     /// let b = RangedU16::<1, 2>::new::<2>();
-    /// let output: RangedU16::<1, 2> = a.div_ranged(b).number().unwrap();
+    /// let output: RangedU16::<1, 5> = a.div_ranged(b).number().unwrap();
     ///
     /// assert_eq!(output.get(), 1);
     /// ```
     ///
+    /// Divisor ranges may contain zero, dividing by which is
+    /// [`Quotient::Nan`]:
+    ///
+    /// ```rust
+    /// # use ranch::RangedU16;
+    /// let a = RangedU16::<0, 6>::new::<6>();
+    /// let b = RangedU16::<0, 3>::new::<3>();
+    /// let output: RangedU16::<0, 6> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// assert!(a.div_ranged::<0, 3, 0, 6>(RangedU16::new::<0>()).is_nan());
+    /// ```
+    ///
     /// Does not compile:
     //
     /// ```compile_fail
@@ -597,11 +631,14 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
         rhs: RangedU16<RHS_MIN, RHS_MAX>,
     ) -> Quotient<RangedU16<OUTPUT_MIN, OUTPUT_MAX>> {
         const {
-            if MIN / RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u16::div_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u16::div_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX / RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -646,11 +683,14 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU16<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN.pow(RHS_MIN) != OUTPUT_MIN {
+            let min = bounds::u16::pow_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u16::pow_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX.pow(RHS_MAX) != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
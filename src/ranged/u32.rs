@@ -1,9 +1,13 @@
 use as_repr::AsRepr;
 
-use crate::{Error, ParsingError, ParsingResult, Quotient, Result};
+use crate::{Error, ParsingError, ParsingResult, Quotient, Result, bounds};
 
 /// [`u32`] with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedU32<const MIN: u32, const MAX: u32>(pub(crate) u32);
 
@@ -214,7 +218,8 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
 
     /// Multiply two ranged integers together.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the product is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU32};
@@ -225,6 +230,7 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
     /// assert_eq!(b.saturating_mul(b).get(), 25);
     /// assert_eq!(a.saturating_mul(c).get(), 100);
     /// assert_eq!(c.saturating_mul(c).get(), 100);
+    /// assert_eq!(RangedU32::<1, 9>::MAX.saturating_mul(0).get(), 1);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -233,7 +239,8 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
 
         match Self::with_u32(self.get().saturating_mul(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -267,7 +274,8 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
 
     /// Raise to an integer power.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the power is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU32};
@@ -278,6 +286,7 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
     /// assert_eq!(a.saturating_pow(2).get(), 100);
     /// assert_eq!(b.saturating_pow(2).get(), 25);
     /// assert_eq!(c.saturating_pow(3).get(), 8);
+    /// assert_eq!(RangedU32::<2, 9>::MAX.saturating_pow(0).get(), 2);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -286,7 +295,8 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
 
         match Self::with_u32(self.get().saturating_pow(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -462,11 +472,14 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU32<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN + RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u32::add_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u32::add_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX + RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -507,11 +520,14 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU32<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN - RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u32::sub_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u32::sub_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX - RHS_MIN != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -552,11 +568,14 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU32<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN * RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u32::mul_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u32::mul_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX * RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -570,11 +589,24 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
     /// # use ranch::RangedU32;
     /// let a = RangedU32::<2, 5>::new::<3>();
     /// let b = RangedU32::<1, 2>::new::<2>();
-    /// let output: RangedU32::<1, 2> = a.div_ranged(b).number().unwrap();
+    /// let output: RangedU32::<1, 5> = a.div_ranged(b).number().unwrap();
     ///
     /// assert_eq!(output.get(), 1);
     /// ```
     ///
+    /// Divisor ranges may contain zero, dividing by which is
+    /// [`Quotient::Nan`]:
+    ///
+    /// ```rust
+    /// # use ranch::RangedU32;
+    /// let a = RangedU32::<0, 6>::new::<6>();
+    /// let b = RangedU32::<0, 3>::new::<3>();
+    /// let output: RangedU32::<0, 6> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// assert!(a.div_ranged::<0, 3, 0, 6>(RangedU32::new::<0>()).is_nan());
+    /// ```
+    ///
     /// Does not compile:
     //
     /// ```compile_fail
@@ -597,11 +629,14 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> Quotient<RangedU32<OUTPUT_MIN, OUTPUT_MAX>> {
         const {
-            if MIN / RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u32::div_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u32::div_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX / RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -646,11 +681,14 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU32<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN.pow(RHS_MIN) != OUTPUT_MIN {
+            let min = bounds::u32::pow_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u32::pow_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX.pow(RHS_MAX) != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -1,9 +1,15 @@
 use as_repr::AsRepr;
 
-use crate::{Error, ParsingError, ParsingResult, Quotient, RangedU32, Result};
+use crate::{
+    Error, ParsingError, ParsingResult, Quotient, RangedU32, Result, bounds,
+};
 
 /// [`u8`] with a specified minimum and maximum value
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct RangedU8<const MIN: u8, const MAX: u8>(pub(crate) u8);
 
@@ -214,7 +220,8 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
 
     /// Multiply two ranged integers together.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the product is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU8};
@@ -225,6 +232,7 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
     /// assert_eq!(b.saturating_mul(b).get(), 25);
     /// assert_eq!(a.saturating_mul(c).get(), 100);
     /// assert_eq!(c.saturating_mul(c).get(), 100);
+    /// assert_eq!(RangedU8::<1, 9>::MAX.saturating_mul(0).get(), 1);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -232,7 +240,8 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         let other = as_repr::as_repr(other);
         match Self::with_u8(self.get().saturating_mul(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -266,7 +275,8 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
 
     /// Raise to an integer power.
     ///
-    /// Returns [`Self::MAX`] on overflow.
+    /// Returns [`Self::MIN`] if the power is below the range, and
+    /// [`Self::MAX`] on overflow.
     ///
     /// ```rust
     /// # use ranch::{Error, RangedU8};
@@ -277,6 +287,7 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
     /// assert_eq!(a.saturating_pow(2).get(), 100);
     /// assert_eq!(b.saturating_pow(2).get(), 25);
     /// assert_eq!(c.saturating_pow(3).get(), 8);
+    /// assert_eq!(RangedU8::<2, 9>::MAX.saturating_pow(0).get(), 2);
     /// ```
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
@@ -285,7 +296,8 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
 
         match Self::with_u8(self.get().saturating_pow(other)) {
             Ok(value) => value,
-            Err(_) => Self::MAX,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
         }
     }
 
@@ -461,11 +473,14 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         rhs: RangedU8<RHS_MIN, RHS_MAX>,
     ) -> RangedU8<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN + RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u8::add_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u8::add_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX + RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -506,11 +521,14 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         rhs: RangedU8<RHS_MIN, RHS_MAX>,
     ) -> RangedU8<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN - RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u8::sub_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u8::sub_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX - RHS_MIN != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -551,11 +569,14 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         rhs: RangedU8<RHS_MIN, RHS_MAX>,
     ) -> RangedU8<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN * RHS_MIN != OUTPUT_MIN {
+            let min = bounds::u8::mul_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u8::mul_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX * RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -569,11 +590,24 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
     /// # use ranch::RangedU8;
     /// let a = RangedU8::<2, 5>::new::<3>();
     /// let b = RangedU8::<1, 2>::new::<2>();
-    /// let output: RangedU8::<1, 2> = a.div_ranged(b).number().unwrap();
+    /// let output: RangedU8::<1, 5> = a.div_ranged(b).number().unwrap();
     ///
     /// assert_eq!(output.get(), 1);
     /// ```
     ///
+    /// Divisor ranges may contain zero, dividing by which is
+    /// [`Quotient::Nan`]:
+    ///
+    /// ```rust
+    /// # use ranch::RangedU8;
+    /// let a = RangedU8::<0, 6>::new::<6>();
+    /// let b = RangedU8::<0, 3>::new::<3>();
+    /// let output: RangedU8::<0, 6> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// assert!(a.div_ranged::<0, 3, 0, 6>(RangedU8::new::<0>()).is_nan());
+    /// ```
+    ///
     /// Does not compile:
     //
     /// ```compile_fail
@@ -596,11 +630,14 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         rhs: RangedU8<RHS_MIN, RHS_MAX>,
     ) -> Quotient<RangedU8<OUTPUT_MIN, OUTPUT_MAX>> {
         const {
-            if MIN / RHS_MAX != OUTPUT_MIN {
+            let min = bounds::u8::div_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u8::div_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX / RHS_MAX != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -645,11 +682,14 @@ impl<const MIN: u8, const MAX: u8> RangedU8<MIN, MAX> {
         rhs: RangedU32<RHS_MIN, RHS_MAX>,
     ) -> RangedU8<OUTPUT_MIN, OUTPUT_MAX> {
         const {
-            if MIN.pow(RHS_MIN) != OUTPUT_MIN {
+            let min = bounds::u8::pow_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::u8::pow_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
                 panic!("Min mismatch");
             }
 
-            if MAX.pow(RHS_MAX) != OUTPUT_MAX {
+            if max != OUTPUT_MAX {
                 panic!("Max mismatch");
             }
         }
@@ -0,0 +1,724 @@
+use as_repr::AsRepr;
+
+use crate::{
+    Error, ParsingError, ParsingResult, Quotient, RangedU32, Result, bounds,
+};
+
+/// [`usize`] with a specified minimum and maximum value
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::KnownLayout)
+)]
+#[repr(transparent)]
+pub struct RangedUsize<const MIN: usize, const MAX: usize>(pub(crate) usize);
+
+impl<const MIN: usize, const MAX: usize> RangedUsize<MIN, MAX> {
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = usize::BITS;
+    /// The largest value that can be represented by this integer type.
+    pub const MAX: Self = Self(MAX);
+    /// The smallest value that can be represented by this integer type.
+    pub const MIN: Self = Self(MIN);
+
+    /// Create a new ranged integer.
+    ///
+    /// Won't compile if out of bounds.
+    ///
+    /// Compiles:
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// RangedUsize::<1, 3>::new::<1>();
+    /// RangedUsize::<1, 3>::new::<2>();
+    /// RangedUsize::<1, 3>::new::<3>();
+    /// ```
+    ///
+    /// Does not compile:
+    ///
+    /// ```compile_fail
+    /// RangedUsize::<1, 3>::new::<0>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// RangedUsize::<1, 3>::new::<4>();
+    /// ```
+    #[must_use]
+    pub const fn new<const N: usize>() -> Self {
+        const {
+            Self::assert_range();
+
+            if N < MIN || N > MAX {
+                panic!("Out of bounds");
+            }
+        }
+
+        Self(N)
+    }
+
+    /// Try to create a new ranged integer.
+    ///
+    /// Returns `Err` if out of bounds.
+    ///
+    /// ```rust
+    /// # use ranch::{RangedUsize, Error};
+    /// RangedUsize::<1, 2>::with_usize(1).unwrap();
+    /// RangedUsize::<1, 2>::with_usize(2).unwrap();
+    /// assert_eq!(RangedUsize::<1, 2>::with_usize(0).unwrap_err(), Error::NegOverflow);
+    /// assert_eq!(RangedUsize::<1, 2>::with_usize(3).unwrap_err(), Error::PosOverflow);
+    /// ```
+    pub const fn with_usize(value: impl AsRepr<usize>) -> Result<Self> {
+        const { Self::assert_range() };
+
+        let value = as_repr::as_repr(value);
+
+        if value < MIN {
+            return Err(Error::NegOverflow);
+        }
+
+        if value > MAX {
+            return Err(Error::PosOverflow);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Return the contained value as a primitive type.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// assert_eq!(42, RangedUsize::<1, 100>::new::<42>().get());
+    /// ```
+    #[must_use]
+    pub const fn get(self) -> usize {
+        self.0
+    }
+
+    /// Return the number of leading zeros in the binary representation of
+    /// `self`.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let n = RangedUsize::<{ usize::MIN }, { usize::MAX }>::MAX;
+    ///
+    /// assert_eq!(n.leading_zeros().get(), 0);
+    /// ```
+    #[must_use]
+    pub const fn leading_zeros(self) -> RangedU32<0, { usize::BITS }> {
+        RangedU32(self.get().leading_zeros())
+    }
+
+    /// Return the number of trailing zeros in the binary representation of
+    /// `self`.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let n = RangedUsize::<0, 255>::new::<0b0101000>();
+    ///
+    /// assert_eq!(n.trailing_zeros().get(), 3);
+    /// ```
+    #[must_use]
+    pub const fn trailing_zeros(self) -> RangedU32<0, { usize::BITS }> {
+        RangedU32(self.get().trailing_zeros())
+    }
+
+    /// Return the number of ones in the binary representation of `self`.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<0, 255>::new::<0b100_0000>();
+    /// let b = RangedUsize::<0, 255>::new::<0b100_0011>();
+    ///
+    /// assert_eq!(a.count_ones().get(), 1);
+    /// assert_eq!(b.count_ones().get(), 3);
+    /// ```
+    #[must_use]
+    pub const fn count_ones(self) -> RangedU32<0, { usize::BITS }> {
+        RangedU32(self.get().count_ones())
+    }
+
+    /// Add two ranged integers together.
+    ///
+    /// Returns [`None`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 100>::new::<50>();
+    /// let b = RangedUsize::<1, 100>::new::<5>();
+    /// let c = a.checked_add(b).unwrap();
+    ///
+    /// assert!(c.checked_add(a).is_none());
+    /// assert_eq!(c.get(), 55);
+    /// assert_eq!(a.checked_add(a).unwrap().get(), 100);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_add(self, other: impl AsRepr<usize>) -> Option<Self> {
+        let other = as_repr::as_repr(other);
+        let Some(value) = self.get().checked_add(other) else {
+            return None;
+        };
+
+        match Self::with_usize(value) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Add two ranged integers together.
+    ///
+    /// Returns [`Self::MAX`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 100>::new::<50>();
+    /// let b = RangedUsize::<1, 100>::new::<5>();
+    /// let c = a.saturating_add(b);
+    ///
+    /// assert_eq!(c.saturating_add(a).get(), 100);
+    /// assert_eq!(c.get(), 55);
+    /// assert_eq!(a.saturating_add(a).get(), 100);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_add(self, other: impl AsRepr<usize>) -> Self {
+        let other = as_repr::as_repr(other);
+
+        match Self::with_usize(self.get().saturating_add(other)) {
+            Ok(value) => value,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    /// Multiply two ranged integers together.
+    ///
+    /// Returns [`None`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize};
+    /// let a = RangedUsize::<0, 100>::new::<50>();
+    /// let b = RangedUsize::<0, 100>::new::<5>();
+    /// let c = RangedUsize::<0, 100>::new::<75>();
+    ///
+    /// assert_eq!(b.checked_mul(b).unwrap().get(), 25);
+    /// assert_eq!(a.checked_mul(c), None);
+    /// assert_eq!(c.checked_mul(c), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_mul(self, other: impl AsRepr<usize>) -> Option<Self> {
+        let other = as_repr::as_repr(other);
+        let Some(value) = self.get().checked_mul(other) else {
+            return None;
+        };
+
+        match Self::with_usize(value) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Multiply two ranged integers together.
+    ///
+    /// Returns [`Self::MIN`] if the product is below the range, and
+    /// [`Self::MAX`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize};
+    /// let a = RangedUsize::<0, 100>::new::<50>();
+    /// let b = RangedUsize::<0, 100>::new::<5>();
+    /// let c = RangedUsize::<0, 100>::new::<75>();
+    ///
+    /// assert_eq!(b.saturating_mul(b).get(), 25);
+    /// assert_eq!(a.saturating_mul(c).get(), 100);
+    /// assert_eq!(c.saturating_mul(c).get(), 100);
+    /// assert_eq!(RangedUsize::<1, 9>::MAX.saturating_mul(0).get(), 1);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_mul(self, other: impl AsRepr<usize>) -> Self {
+        let other = as_repr::as_repr(other);
+
+        match Self::with_usize(self.get().saturating_mul(other)) {
+            Ok(value) => value,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
+        }
+    }
+
+    /// Raise to an integer power.
+    ///
+    /// Returns [`None`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize};
+    /// let a = RangedUsize::<0, 100>::new::<50>();
+    /// let b = RangedUsize::<0, 100>::new::<5>();
+    /// let c = RangedUsize::<0, 100>::new::<2>();
+    ///
+    /// assert_eq!(a.checked_pow(2), None);
+    /// assert_eq!(b.checked_pow(2).unwrap().get(), 25);
+    /// assert_eq!(c.checked_pow(3).unwrap().get(), 8);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_pow(self, other: impl AsRepr<u32>) -> Option<Self> {
+        let other = as_repr::as_repr(other);
+        let Some(value) = self.get().checked_pow(other) else {
+            return None;
+        };
+
+        match Self::with_usize(value) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Raise to an integer power.
+    ///
+    /// Returns [`Self::MIN`] if the power is below the range, and
+    /// [`Self::MAX`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize};
+    /// let a = RangedUsize::<0, 100>::new::<50>();
+    /// let b = RangedUsize::<0, 100>::new::<5>();
+    /// let c = RangedUsize::<0, 100>::new::<2>();
+    ///
+    /// assert_eq!(a.saturating_pow(2).get(), 100);
+    /// assert_eq!(b.saturating_pow(2).get(), 25);
+    /// assert_eq!(c.saturating_pow(3).get(), 8);
+    /// assert_eq!(RangedUsize::<2, 9>::MAX.saturating_pow(0).get(), 2);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_pow(self, other: impl AsRepr<u32>) -> Self {
+        let other = as_repr::as_repr(other);
+
+        match Self::with_usize(self.get().saturating_pow(other)) {
+            Ok(value) => value,
+            Err(Error::NegOverflow) => Self::MIN,
+            Err(Error::PosOverflow) => Self::MAX,
+        }
+    }
+
+    /// Checked integer division.
+    ///
+    /// Returns [`None`] on overflow; [`Quotient::Nan`] if `rhs == 0`.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize, Quotient};
+    /// let a = RangedUsize::<1, 50>::new::<50>();
+    /// let b = RangedUsize::<1, 50>::new::<1>();
+    ///
+    /// assert_eq!(
+    ///     a.checked_div(2),
+    ///     Some(Quotient::Number(RangedUsize::new::<25>())),
+    /// );
+    /// assert_eq!(a.checked_div(0), Some(Quotient::Nan));
+    /// assert_eq!(b.checked_div(2), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_div(
+        self,
+        rhs: impl AsRepr<usize>,
+    ) -> Option<Quotient<Self>> {
+        let rhs = as_repr::as_repr(rhs);
+        let Some(value) = self.get().checked_div(rhs) else {
+            return Some(Quotient::Nan);
+        };
+
+        match Self::with_usize(value) {
+            Ok(value) => Some(Quotient::Number(value)),
+            Err(_) => None,
+        }
+    }
+
+    /// Saturating integer division.
+    ///
+    /// Returns [`Self::MIN`] on overflow, and [`Quotient::Nan`] if `rhs` is 0.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize, Quotient};
+    /// let a = RangedUsize::<1, 50>::new::<50>();
+    /// let b = RangedUsize::<1, 50>::new::<1>();
+    ///
+    /// assert_eq!(
+    ///     a.saturating_div(2),
+    ///     Quotient::Number(RangedUsize::new::<25>()),
+    /// );
+    /// assert_eq!(a.saturating_div(0), Quotient::Nan);
+    /// assert_eq!(
+    ///     b.saturating_div(2),
+    ///     Quotient::Number(RangedUsize::new::<1>()),
+    /// );
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_div(
+        self,
+        rhs: impl AsRepr<usize>,
+    ) -> Quotient<Self> {
+        let rhs = as_repr::as_repr(rhs);
+
+        if rhs == 0 {
+            return Quotient::Nan;
+        }
+
+        Quotient::Number(
+            match Self::with_usize(self.get().saturating_div(rhs)) {
+                Ok(value) => value,
+                Err(_) => Self::MIN,
+            },
+        )
+    }
+
+    /// Subtract a ranged integers from another.
+    ///
+    /// Returns [`None`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 100>::new::<50>();
+    /// let b = a.checked_sub(5).unwrap();
+    ///
+    /// assert_eq!(b.get(), 45);
+    /// assert!(a.checked_sub(a).is_none());
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn checked_sub(self, other: impl AsRepr<usize>) -> Option<Self> {
+        let other = as_repr::as_repr(other);
+        let Some(value) = self.get().checked_sub(other) else {
+            return None;
+        };
+
+        match Self::with_usize(value) {
+            Ok(value) => Some(value),
+            Err(_) => None,
+        }
+    }
+
+    /// Subtract a ranged integers from another.
+    ///
+    /// Returns [`Self::MIN`] on overflow.
+    ///
+    /// ```rust
+    /// # use ranch::{Error, RangedUsize};
+    /// let a = RangedUsize::<1, 100>::new::<50>();
+    /// let b = a.saturating_sub(5);
+    ///
+    /// assert_eq!(b.get(), 45);
+    /// assert_eq!(a.saturating_sub(a).get(), 1);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn saturating_sub(self, other: impl AsRepr<usize>) -> Self {
+        let other = as_repr::as_repr(other);
+
+        match Self::with_usize(self.get().saturating_sub(other)) {
+            Ok(value) => value,
+            Err(_) => Self::MIN,
+        }
+    }
+
+    /// Calculate the midpoint (average) between `self` and `rhs`.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<0, 8>::new::<0>();
+    /// let b = RangedUsize::<0, 8>::new::<2>();
+    /// let c = RangedUsize::<0, 8>::new::<4>();
+    /// let d = RangedUsize::<0, 8>::new::<3>();
+    /// let e = RangedUsize::<0, 8>::new::<7>();
+    ///
+    /// assert_eq!(a.midpoint(c), b);
+    /// assert_eq!(a.midpoint(e), d);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn midpoint(self, rhs: Self) -> Self {
+        let Ok(value) = Self::with_usize(self.get().midpoint(rhs.get())) else {
+            panic!("unexpected midpoint value")
+        };
+
+        value
+    }
+
+    /// Add two numbers together.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 3>::new::<1>();
+    /// let b = RangedUsize::<1, 3>::new::<2>();
+    /// let output: RangedUsize::<2, 6> = a.add_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 3);
+    /// ```
+    ///
+    /// Does not compile:
+    ///
+    /// ```compile_fail
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 3>::new::<1>();
+    /// let b = RangedUsize::<1, 3>::new::<2>();
+    /// let output: RangedUsize::<1, 6> = a.add_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 3);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn add_ranged<
+        const RHS_MIN: usize,
+        const RHS_MAX: usize,
+        const OUTPUT_MIN: usize,
+        const OUTPUT_MAX: usize,
+    >(
+        self,
+        rhs: RangedUsize<RHS_MIN, RHS_MAX>,
+    ) -> RangedUsize<OUTPUT_MIN, OUTPUT_MAX> {
+        const {
+            let min = bounds::usize::add_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::usize::add_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
+                panic!("Min mismatch");
+            }
+
+            if max != OUTPUT_MAX {
+                panic!("Max mismatch");
+            }
+        }
+
+        RangedUsize(self.get() + rhs.get())
+    }
+
+    /// Subtract a number from `self`.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<2, 5>::new::<3>();
+    /// let b = RangedUsize::<1, 2>::new::<1>();
+    /// let output: RangedUsize::<0, 4> = a.sub_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// ```
+    ///
+    /// Does not compile:
+    ///
+    /// ```compile_fail
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<2, 5>::new::<3>();
+    /// let b = RangedUsize::<1, 2>::new::<1>();
+    /// let output: RangedUsize::<0, 3> = a.sub_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn sub_ranged<
+        const RHS_MIN: usize,
+        const RHS_MAX: usize,
+        const OUTPUT_MIN: usize,
+        const OUTPUT_MAX: usize,
+    >(
+        self,
+        rhs: RangedUsize<RHS_MIN, RHS_MAX>,
+    ) -> RangedUsize<OUTPUT_MIN, OUTPUT_MAX> {
+        const {
+            let min = bounds::usize::sub_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::usize::sub_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
+                panic!("Min mismatch");
+            }
+
+            if max != OUTPUT_MAX {
+                panic!("Max mismatch");
+            }
+        }
+
+        RangedUsize(self.get() - rhs.get())
+    }
+
+    /// Multiply two numbers together.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 3>::new::<1>();
+    /// let b = RangedUsize::<2, 3>::new::<2>();
+    /// let output: RangedUsize::<2, 9> = a.mul_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// ```
+    ///
+    /// Does not compile:
+    ///
+    /// ```compile_fail
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<1, 3>::new::<1>();
+    /// let b = RangedUsize::<2, 3>::new::<2>();
+    /// let output: RangedUsize::<1, 9> = a.mul_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn mul_ranged<
+        const RHS_MIN: usize,
+        const RHS_MAX: usize,
+        const OUTPUT_MIN: usize,
+        const OUTPUT_MAX: usize,
+    >(
+        self,
+        rhs: RangedUsize<RHS_MIN, RHS_MAX>,
+    ) -> RangedUsize<OUTPUT_MIN, OUTPUT_MAX> {
+        const {
+            let min = bounds::usize::mul_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::usize::mul_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
+                panic!("Min mismatch");
+            }
+
+            if max != OUTPUT_MAX {
+                panic!("Max mismatch");
+            }
+        }
+
+        RangedUsize(self.get() * rhs.get())
+    }
+
+    /// Divide `self` by a number.
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<2, 5>::new::<3>();
+    /// let b = RangedUsize::<1, 2>::new::<2>();
+    /// let output: RangedUsize::<1, 5> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 1);
+    /// ```
+    ///
+    /// Divisor ranges may contain zero, dividing by which is
+    /// [`Quotient::Nan`]:
+    ///
+    /// ```rust
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<0, 6>::new::<6>();
+    /// let b = RangedUsize::<0, 3>::new::<3>();
+    /// let output: RangedUsize::<0, 6> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 2);
+    /// assert!(a.div_ranged::<0, 3, 0, 6>(RangedUsize::new::<0>()).is_nan());
+    /// ```
+    ///
+    /// Does not compile:
+    //
+    /// ```compile_fail
+    /// # use ranch::RangedUsize;
+    /// let a = RangedUsize::<2, 5>::new::<3>();
+    /// let b = RangedUsize::<1, 2>::new::<1>();
+    /// let output: RangedUsize::<0, 2> = a.div_ranged(b).number().unwrap();
+    ///
+    /// assert_eq!(output.get(), 1);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn div_ranged<
+        const RHS_MIN: usize,
+        const RHS_MAX: usize,
+        const OUTPUT_MIN: usize,
+        const OUTPUT_MAX: usize,
+    >(
+        self,
+        rhs: RangedUsize<RHS_MIN, RHS_MAX>,
+    ) -> Quotient<RangedUsize<OUTPUT_MIN, OUTPUT_MAX>> {
+        const {
+            let min = bounds::usize::div_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::usize::div_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
+                panic!("Min mismatch");
+            }
+
+            if max != OUTPUT_MAX {
+                panic!("Max mismatch");
+            }
+        }
+
+        if rhs.get() == 0 {
+            Quotient::Nan
+        } else {
+            Quotient::Number(RangedUsize(self.get() / rhs.get()))
+        }
+    }
+
+    /// Raise to an integer power.
+    ///
+    /// ```rust
+    /// # use ranch::{RangedUsize, RangedU32};
+    /// let a = RangedUsize::<1, 3>::new::<2>();
+    /// let b = RangedU32::<2, 3>::new::<2>();
+    /// let output: RangedUsize::<1, 27> = a.pow_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 4);
+    /// ```
+    ///
+    /// Does not compile:
+    ///
+    /// ```compile_fail
+    /// # use ranch::{RangedUsize, RangedU32};
+    /// let a = RangedUsize::<1, 3>::new::<2>();
+    /// let b = RangedU32::<2, 3>::new::<2>();
+    /// let output: RangedUsize::<0, 27> = a.pow_ranged(b);
+    ///
+    /// assert_eq!(output.get(), 4);
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn pow_ranged<
+        const RHS_MIN: u32,
+        const RHS_MAX: u32,
+        const OUTPUT_MIN: usize,
+        const OUTPUT_MAX: usize,
+    >(
+        self,
+        rhs: RangedU32<RHS_MIN, RHS_MAX>,
+    ) -> RangedUsize<OUTPUT_MIN, OUTPUT_MAX> {
+        const {
+            let min = bounds::usize::pow_min(MIN, MAX, RHS_MIN, RHS_MAX);
+            let max = bounds::usize::pow_max(MIN, MAX, RHS_MIN, RHS_MAX);
+
+            if min != OUTPUT_MIN {
+                panic!("Min mismatch");
+            }
+
+            if max != OUTPUT_MAX {
+                panic!("Max mismatch");
+            }
+        }
+
+        RangedUsize(self.get().pow(rhs.get()))
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> core::str::FromStr
+    for RangedUsize<MIN, MAX>
+{
+    type Err = ParsingError;
+
+    fn from_str(src: &str) -> ParsingResult<Self> {
+        let parsed = src.parse::<usize>()?;
+
+        Self::with_usize(parsed).map_err(From::from)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> crate::error::Clamp
+    for RangedUsize<MIN, MAX>
+{
+    const MAX: Self = Self::MAX;
+    const MIN: Self = Self::MIN;
+}
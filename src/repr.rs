@@ -19,6 +19,18 @@ macro_rules! as_repr {
             for $ranged<MIN, MAX>
         {
         }
+
+        // unsafe: `repr(transparent)` on wrappers is `repr(primitive)`
+        unsafe impl<const MIN: $p, const MAX: $p> AsRepr<$p>
+            for Wrapping<$ranged<MIN, MAX>>
+        {
+        }
+
+        // unsafe: `repr(transparent)` on wrappers is `repr(primitive)`
+        unsafe impl<const MIN: $p, const MAX: $p> AsRepr<$p>
+            for Saturating<$ranged<MIN, MAX>>
+        {
+        }
     };
 }
 
@@ -44,19 +56,23 @@ as_repr!(RangedU16, u16);
 as_repr!(RangedU32, u32);
 as_repr!(RangedU64, u64);
 as_repr!(RangedU128, u128);
+as_repr!(RangedUsize, usize);
 as_repr!(RangedI8, i8);
 as_repr!(RangedI16, i16);
 as_repr!(RangedI32, i32);
 as_repr!(RangedI64, i64);
 as_repr!(RangedI128, i128);
+as_repr!(RangedIsize, isize);
 
 as_repr_nonzero!(RangedNonZeroU8, u8);
 as_repr_nonzero!(RangedNonZeroU16, u16);
 as_repr_nonzero!(RangedNonZeroU32, u32);
 as_repr_nonzero!(RangedNonZeroU64, u64);
 as_repr_nonzero!(RangedNonZeroU128, u128);
+as_repr_nonzero!(RangedNonZeroUsize, usize);
 as_repr_nonzero!(RangedNonZeroI8, i8);
 as_repr_nonzero!(RangedNonZeroI16, i16);
 as_repr_nonzero!(RangedNonZeroI32, i32);
 as_repr_nonzero!(RangedNonZeroI64, i64);
 as_repr_nonzero!(RangedNonZeroI128, i128);
+as_repr_nonzero!(RangedNonZeroIsize, isize);
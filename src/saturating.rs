@@ -0,0 +1,191 @@
+use core::{
+    num::NonZero,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+
+use as_repr::AsRepr;
+
+use super::*;
+
+/// Intentionally saturating arithmetic on ranged integers
+///
+/// Operations like `+` on [`Saturating`] values saturate at the bounds of the
+/// contained type, rather than panicking when out of range.  This mirrors
+/// [`core::num::Saturating`].
+///
+/// ```rust
+/// # use ranch::{RangedI16, Saturating};
+/// let mut gain = Saturating(RangedI16::<-100, 100>::new::<90>());
+///
+/// gain += 20;
+/// assert_eq!(gain, Saturating(RangedI16::new::<100>()));
+/// gain *= -3;
+/// assert_eq!(gain, Saturating(RangedI16::new::<-100>()));
+/// assert_eq!(gain - gain, Saturating(RangedI16::new::<0>()));
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! impl_saturating {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Saturating<$type<MIN, MAX>> {
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self($type::MAX);
+            /// The smallest value that can be represented by this integer
+            /// type.
+            pub const MIN: Self = Self($type::MIN);
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Add<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn add(self, other: T) -> Self {
+                Self(self.0.saturating_add(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Sub<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn sub(self, other: T) -> Self {
+                Self(self.0.saturating_sub(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Mul<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn mul(self, other: T) -> Self {
+                Self(self.0.saturating_mul(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Div<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn div(self, other: T) -> Self {
+                Self(
+                    self.0
+                        .saturating_div(other)
+                        .number()
+                        .expect("cannot divide by zero"),
+                )
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Rem<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<NonZero<$p>>,
+        {
+            type Output = Self;
+
+            fn rem(self, other: T) -> Self {
+                Self(self.0.saturating_rem(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> AddAssign<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn add_assign(&mut self, other: T) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> SubAssign<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn sub_assign(&mut self, other: T) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> MulAssign<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn mul_assign(&mut self, other: T) {
+                *self = *self * other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> DivAssign<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn div_assign(&mut self, other: T) {
+                *self = *self / other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> RemAssign<T>
+            for Saturating<$type<MIN, MAX>>
+        where
+            T: AsRepr<NonZero<$p>>,
+        {
+            fn rem_assign(&mut self, other: T) {
+                *self = *self % other;
+            }
+        }
+    };
+}
+
+macro_rules! impl_saturating_neg {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Neg for Saturating<$type<MIN, MAX>> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+    };
+}
+
+impl_saturating!(RangedI8, i8);
+impl_saturating!(RangedI16, i16);
+impl_saturating!(RangedI32, i32);
+impl_saturating!(RangedI64, i64);
+impl_saturating!(RangedI128, i128);
+impl_saturating!(RangedIsize, isize);
+
+impl_saturating!(RangedU8, u8);
+impl_saturating!(RangedU16, u16);
+impl_saturating!(RangedU32, u32);
+impl_saturating!(RangedU64, u64);
+impl_saturating!(RangedU128, u128);
+impl_saturating!(RangedUsize, usize);
+
+impl_saturating_neg!(RangedI8, i8);
+impl_saturating_neg!(RangedI16, i16);
+impl_saturating_neg!(RangedI32, i32);
+impl_saturating_neg!(RangedI64, i64);
+impl_saturating_neg!(RangedI128, i128);
+impl_saturating_neg!(RangedIsize, isize);
@@ -1,9 +1,41 @@
-use serde_core::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+//! Range-validated serialization and deserialization
+//!
+//! Every ranged integer is (de)serialized as its primitive integer.  When
+//! deserializing, any integer width is accepted, and out of range integers are
+//! reported with the accepted range.
+//!
+//! ```rust
+//! # use ranch::RangedU8;
+//! # use serde::Deserialize;
+//! #[derive(Debug, Deserialize)]
+//! struct Clock {
+//!     hour: RangedU8<1, 12>,
+//! }
+//!
+//! let clock: Clock = serde_json::from_str(r#"{ "hour": 12 }"#).unwrap();
+//! let late = serde_json::from_str::<Clock>(r#"{ "hour": 13 }"#);
+//! let early = serde_json::from_str::<Clock>(r#"{ "hour": -1 }"#);
+//!
+//! assert_eq!(clock.hour, RangedU8::new::<12>());
+//! assert!(late.unwrap_err().to_string().starts_with(
+//!     "expected integer in 1..=12, got 13",
+//! ));
+//! assert!(early.unwrap_err().to_string().starts_with(
+//!     "expected integer in 1..=12, got -1",
+//! ));
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use serde_core::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, Unexpected, Visitor},
+};
 
 use super::*;
 
 macro_rules! impl_serde {
-    ($type:ident, $p:ty) => {
+    ($type:ident, $p:ty, $deserialize:ident $(,)?) => {
         impl<'de, const MIN: $p, const MAX: $p> Deserialize<'de>
             for $type<MIN, MAX>
         {
@@ -11,9 +43,7 @@ macro_rules! impl_serde {
             where
                 D: Deserializer<'de>,
             {
-                <$p as Deserialize>::deserialize(deserializer)?
-                    .try_into()
-                    .map_err(Error::custom)
+                deserializer.$deserialize(RangedVisitor(PhantomData))
             }
         }
 
@@ -22,63 +52,157 @@ macro_rules! impl_serde {
             where
                 S: Serializer,
             {
-                <$p as Serialize>::serialize(&self.get(), serializer)
+                self.get().serialize(serializer)
             }
         }
     };
 }
 
-macro_rules! impl_serde_nonzero {
-    ($type:ident, $p:ty, $r:ident) => {
-        impl<'de, const MIN: $p, const MAX: $p> Deserialize<'de>
-            for $type<MIN, MAX>
-        {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: Deserializer<'de>,
-            {
-                let r: $r<MIN, MAX> =
-                    <$p as Deserialize>::deserialize(deserializer)?
-                        .try_into()
-                        .map_err(Error::custom)?;
-
-                r.to_ranged_nonzero()
-                    .ok_or("must not be zero")
-                    .map_err(Error::custom)
-            }
-        }
+impl_serde!(RangedU8, u8, deserialize_u8);
+impl_serde!(RangedU16, u16, deserialize_u16);
+impl_serde!(RangedU32, u32, deserialize_u32);
+impl_serde!(RangedU64, u64, deserialize_u64);
+impl_serde!(RangedU128, u128, deserialize_u128);
+impl_serde!(RangedUsize, usize, deserialize_u64);
 
-        impl<const MIN: $p, const MAX: $p> Serialize for $type<MIN, MAX> {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: Serializer,
-            {
-                <$p as Serialize>::serialize(&self.get(), serializer)
-            }
+impl_serde!(RangedI8, i8, deserialize_i8);
+impl_serde!(RangedI16, i16, deserialize_i16);
+impl_serde!(RangedI32, i32, deserialize_i32);
+impl_serde!(RangedI64, i64, deserialize_i64);
+impl_serde!(RangedI128, i128, deserialize_i128);
+impl_serde!(RangedIsize, isize, deserialize_i64);
+
+impl_serde!(RangedNonZeroU8, u8, deserialize_u8);
+impl_serde!(RangedNonZeroU16, u16, deserialize_u16);
+impl_serde!(RangedNonZeroU32, u32, deserialize_u32);
+impl_serde!(RangedNonZeroU64, u64, deserialize_u64);
+impl_serde!(RangedNonZeroU128, u128, deserialize_u128);
+impl_serde!(RangedNonZeroUsize, usize, deserialize_u64);
+
+impl_serde!(RangedNonZeroI8, i8, deserialize_i8);
+impl_serde!(RangedNonZeroI16, i16, deserialize_i16);
+impl_serde!(RangedNonZeroI32, i32, deserialize_i32);
+impl_serde!(RangedNonZeroI64, i64, deserialize_i64);
+impl_serde!(RangedNonZeroI128, i128, deserialize_i128);
+impl_serde!(RangedNonZeroIsize, isize, deserialize_i64);
+
+/// (De)serialize a ranged integer as a string
+///
+/// For formats that can't represent every integer as a number, such as
+/// [`u128`] in JSON.  Use with `#[serde(with = "ranch::serde::as_string")]`.
+///
+/// ```rust
+/// # use ranch::RangedU128;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     #[serde(with = "ranch::serde::as_string")]
+///     balance: RangedU128<0, { u128::MAX - 1 }>,
+/// }
+///
+/// let json = r#"{"balance":"340282366920938463463374607431768211454"}"#;
+/// let account: Account = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(account.balance.get(), u128::MAX - 1);
+/// assert_eq!(serde_json::to_string(&account).unwrap(), json);
+/// ```
+pub mod as_string {
+    use super::*;
+
+    /// Serialize a ranged integer as a string.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: RangedInt,
+        S: Serializer,
+    {
+        serializer.collect_str(&value.get())
+    }
+
+    /// Deserialize a ranged integer from a string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: RangedInt,
+        T::Primitive: TryFrom<i128> + TryFrom<u128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RangedVisitor(PhantomData))
+    }
+}
+
+/// Visitor accepting integers of any width, checked against the range
+struct RangedVisitor<T>(PhantomData<T>);
+
+impl<T> RangedVisitor<T>
+where
+    T: RangedInt,
+    T::Primitive: TryFrom<i128> + TryFrom<u128>,
+{
+    fn visit<V, E>(value: V) -> Result<T, E>
+    where
+        V: Copy + fmt::Display,
+        T::Primitive: TryFrom<V>,
+        E: Error,
+    {
+        let (min, max) = (T::MIN.get(), T::MAX.get());
+        let primitive = T::Primitive::try_from(value)
+            .map_err(|_| range::Error::PosOverflow);
+
+        primitive.and_then(T::with_primitive).map_err(|_| {
+            E::custom(format_args!(
+                "expected {} in {min}..={max}, got {value}",
+                Self::kind(),
+            ))
+        })
+    }
+
+    /// What kind of integer is expected, depending on if zero is rejected
+    /// regardless of range
+    fn kind() -> &'static str {
+        let zero = T::Primitive::try_from(0_u128).ok().map(T::with_primitive);
+
+        if let Some(Err(range::Error::Zero)) = zero {
+            "non-zero integer"
+        } else {
+            "integer"
         }
-    };
+    }
 }
 
-impl_serde!(RangedU8, u8);
-impl_serde!(RangedU16, u16);
-impl_serde!(RangedU32, u32);
-impl_serde!(RangedU64, u64);
-impl_serde!(RangedU128, u128);
-
-impl_serde!(RangedI8, i8);
-impl_serde!(RangedI16, i16);
-impl_serde!(RangedI32, i32);
-impl_serde!(RangedI64, i64);
-impl_serde!(RangedI128, i128);
-
-impl_serde_nonzero!(RangedNonZeroU8, u8, RangedU8);
-impl_serde_nonzero!(RangedNonZeroU16, u16, RangedU16);
-impl_serde_nonzero!(RangedNonZeroU32, u32, RangedU32);
-impl_serde_nonzero!(RangedNonZeroU64, u64, RangedU64);
-impl_serde_nonzero!(RangedNonZeroU128, u128, RangedU128);
-
-impl_serde_nonzero!(RangedNonZeroI8, i8, RangedI8);
-impl_serde_nonzero!(RangedNonZeroI16, i16, RangedI16);
-impl_serde_nonzero!(RangedNonZeroI32, i32, RangedI32);
-impl_serde_nonzero!(RangedNonZeroI64, i64, RangedI64);
-impl_serde_nonzero!(RangedNonZeroI128, i128, RangedI128);
+impl<T> Visitor<'_> for RangedVisitor<T>
+where
+    T: RangedInt,
+    T::Primitive: TryFrom<i128> + TryFrom<u128>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in {}..={}", Self::kind(), T::MIN.get(), T::MAX.get())
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<T, E> {
+        Self::visit(i128::from(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<T, E> {
+        Self::visit(u128::from(value))
+    }
+
+    fn visit_i128<E: Error>(self, value: i128) -> Result<T, E> {
+        Self::visit(value)
+    }
+
+    fn visit_u128<E: Error>(self, value: u128) -> Result<T, E> {
+        Self::visit(value)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        if let Ok(value) = value.parse::<i128>() {
+            return Self::visit(value);
+        }
+
+        match value.parse::<u128>() {
+            Ok(value) => Self::visit(value),
+            Err(_) => Err(E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use core::iter::{Product, Sum};
+
+use as_repr::AsRepr;
+
+use super::{
+    ops::{signed_checked, unsigned_checked},
+    *,
+};
+
+macro_rules! impl_sum {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Sum<$type<MIN, MAX>> for $p {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = $type<MIN, MAX>>,
+            {
+                iter.fold(0, |sum, item| {
+                    sum.checked_add(item.get()).expect("out of range")
+                })
+            }
+        }
+
+        impl<'a, const MIN: $p, const MAX: $p> Sum<&'a $type<MIN, MAX>> for $p {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a $type<MIN, MAX>>,
+            {
+                iter.copied().sum()
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> Product<$type<MIN, MAX>> for $p {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = $type<MIN, MAX>>,
+            {
+                iter.fold(1, |product, item| {
+                    product.checked_mul(item.get()).expect("out of range")
+                })
+            }
+        }
+
+        impl<'a, const MIN: $p, const MAX: $p> Product<&'a $type<MIN, MAX>>
+            for $p
+        {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a $type<MIN, MAX>>,
+            {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_sum {
+    (
+        $type:ident,
+        $p:ty,
+        $wide:ty,
+        $with:ident,
+        $ret:ident,
+        $checked:ident,
+        $overflow:literal $(,)?
+    ) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Add up every number in an iterator.
+            ///
+            /// Returns an error if the sum is out of range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            #[doc = concat!("let percents = [", stringify!($type), "::<0, 100>::new::<40>(); 3];")]
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($type), "::<0, 120>::checked_sum(percents.into_iter().take(2)).unwrap().get(),")]
+            ///     80,
+            /// );
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<0, 100>::checked_sum(percents), ", $overflow, ");")]
+            /// ```
+            pub fn checked_sum<I>(iter: I) -> $ret<Self>
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                $checked(Self::sum_result(iter))
+            }
+
+            /// Add up every number in an iterator.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let percents = [", stringify!($type), "::<0, 100>::new::<40>(); 3];")]
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($type), "::<0, 100>::saturating_sum(percents).get(),")]
+            ///     100,
+            /// );
+            /// ```
+            pub fn saturating_sum<I>(iter: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                match Self::sum_result(iter) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            /// Multiply together every number in an iterator.
+            ///
+            /// Returns an error if the product is out of range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{Error, ", stringify!($type), "};")]
+            /// let factors = [2, 3, 4];
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($type), "::<1, 24>::checked_product(factors).unwrap().get(),")]
+            ///     24,
+            /// );
+            #[doc = concat!("assert_eq!(", stringify!($type), "::<1, 20>::checked_product(factors), ", $overflow, ");")]
+            /// ```
+            pub fn checked_product<I>(iter: I) -> $ret<Self>
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                $checked(Self::product_result(iter))
+            }
+
+            /// Multiply together every number in an iterator.
+            ///
+            /// Returns [`Self::MIN`] on negative overflow, and [`Self::MAX`] on
+            /// positive overflow.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            /// let factors = [2, 3, 4];
+            ///
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($type), "::<1, 20>::saturating_product(factors).get(),")]
+            ///     20,
+            /// );
+            /// ```
+            pub fn saturating_product<I>(iter: I) -> Self
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                match Self::product_result(iter) {
+                    Ok(value) => value,
+                    Err(Error::NegOverflow) => Self::MIN,
+                    Err(Error::PosOverflow) => Self::MAX,
+                }
+            }
+
+            // `item` and `sum` comparisons only needed for signed numbers, and
+            // casts are only trivial for 128-bit numbers
+            #[allow(unused_comparisons, trivial_numeric_casts)]
+            fn sum_result<I>(iter: I) -> Result<Self>
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                // the sum can only be out of range of the wide type when it
+                // has wrapped around more times in one direction than the other
+                let mut sum: $wide = 0;
+                let mut wraps: isize = 0;
+
+                for item in iter {
+                    let item = as_repr::as_repr(item) as $wide;
+                    let (value, overflow) = sum.overflowing_add(item);
+
+                    if overflow {
+                        wraps += if item < 0 { -1 } else { 1 };
+                    }
+
+                    sum = value;
+                }
+
+                if wraps != 0 {
+                    return Err(if wraps < 0 {
+                        Error::NegOverflow
+                    } else {
+                        Error::PosOverflow
+                    });
+                }
+
+                match <$p>::try_from(sum) {
+                    Ok(sum) => Self::$with(sum),
+                    Err(_) if sum < 0 => Err(Error::NegOverflow),
+                    Err(_) => Err(Error::PosOverflow),
+                }
+            }
+
+            // `item` and `product` comparisons only needed for signed numbers,
+            // and casts are only trivial for 128-bit numbers
+            #[allow(unused_comparisons, trivial_numeric_casts)]
+            fn product_result<I>(iter: I) -> Result<Self>
+            where
+                I: IntoIterator,
+                I::Item: AsRepr<$p>,
+            {
+                // once out of range of the wide type, the magnitude of the
+                // product can only grow until multiplied by zero
+                let mut product: Option<$wide> = Some(1);
+                let mut negative = false;
+
+                for item in iter {
+                    let item = as_repr::as_repr(item) as $wide;
+
+                    if item == 0 {
+                        return Self::$with(0);
+                    }
+
+                    negative ^= item < 0;
+                    product = match product {
+                        Some(product) => product.checked_mul(item),
+                        None => None,
+                    };
+                }
+
+                let overflow = if negative {
+                    Error::NegOverflow
+                } else {
+                    Error::PosOverflow
+                };
+                let Some(product) = product else {
+                    return Err(overflow);
+                };
+
+                match <$p>::try_from(product) {
+                    Ok(product) => Self::$with(product),
+                    Err(_) => Err(overflow),
+                }
+            }
+        }
+    };
+}
+
+impl_sum!(RangedI8, i8);
+impl_sum!(RangedI16, i16);
+impl_sum!(RangedI32, i32);
+impl_sum!(RangedI64, i64);
+impl_sum!(RangedI128, i128);
+impl_sum!(RangedIsize, isize);
+impl_sum!(RangedU8, u8);
+impl_sum!(RangedU16, u16);
+impl_sum!(RangedU32, u32);
+impl_sum!(RangedU64, u64);
+impl_sum!(RangedU128, u128);
+impl_sum!(RangedUsize, usize);
+
+impl_sum!(RangedNonZeroI8, i8);
+impl_sum!(RangedNonZeroI16, i16);
+impl_sum!(RangedNonZeroI32, i32);
+impl_sum!(RangedNonZeroI64, i64);
+impl_sum!(RangedNonZeroI128, i128);
+impl_sum!(RangedNonZeroIsize, isize);
+impl_sum!(RangedNonZeroU8, u8);
+impl_sum!(RangedNonZeroU16, u16);
+impl_sum!(RangedNonZeroU32, u32);
+impl_sum!(RangedNonZeroU64, u64);
+impl_sum!(RangedNonZeroU128, u128);
+impl_sum!(RangedNonZeroUsize, usize);
+
+impl_checked_sum!(
+    RangedI8,
+    i8,
+    i128,
+    with_i8,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedI16,
+    i16,
+    i128,
+    with_i16,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedI32,
+    i32,
+    i128,
+    with_i32,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedI64,
+    i64,
+    i128,
+    with_i64,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedI128,
+    i128,
+    i128,
+    with_i128,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedIsize,
+    isize,
+    i128,
+    with_isize,
+    Result,
+    signed_checked,
+    "Err(Error::PosOverflow)",
+);
+impl_checked_sum!(
+    RangedU8,
+    u8,
+    u128,
+    with_u8,
+    Option,
+    unsigned_checked,
+    "None",
+);
+impl_checked_sum!(
+    RangedU16,
+    u16,
+    u128,
+    with_u16,
+    Option,
+    unsigned_checked,
+    "None",
+);
+impl_checked_sum!(
+    RangedU32,
+    u32,
+    u128,
+    with_u32,
+    Option,
+    unsigned_checked,
+    "None",
+);
+impl_checked_sum!(
+    RangedU64,
+    u64,
+    u128,
+    with_u64,
+    Option,
+    unsigned_checked,
+    "None",
+);
+impl_checked_sum!(
+    RangedUsize,
+    usize,
+    u128,
+    with_usize,
+    Option,
+    unsigned_checked,
+    "None",
+);
+impl_checked_sum!(
+    RangedU128,
+    u128,
+    u128,
+    with_u128,
+    Option,
+    unsigned_checked,
+    "None",
+);
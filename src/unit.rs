@@ -16,6 +16,8 @@ pub type UnitI32<const VAL: i32> = RangedI32<VAL, VAL>;
 pub type UnitI64<const VAL: i64> = RangedI64<VAL, VAL>;
 /// [`i128`] that's guaranteed to be a specific value
 pub type UnitI128<const VAL: i128> = RangedI128<VAL, VAL>;
+/// [`isize`] that's guaranteed to be a specific value
+pub type UnitIsize<const VAL: isize> = RangedIsize<VAL, VAL>;
 
 /// [`u8`] that's guaranteed to be a specific value
 pub type UnitU8<const VAL: i8> = RangedU8<VAL, VAL>;
@@ -27,6 +29,8 @@ pub type UnitU32<const VAL: i32> = RangedU32<VAL, VAL>;
 pub type UnitU64<const VAL: i64> = RangedU64<VAL, VAL>;
 /// [`u128`] that's guaranteed to be a specific value
 pub type UnitU128<const VAL: i128> = RangedU128<VAL, VAL>;
+/// [`usize`] that's guaranteed to be a specific value
+pub type UnitUsize<const VAL: usize> = RangedUsize<VAL, VAL>;
 
 /// [`i8`] that's guaranteed to be a specific non-zero value
 pub type UnitNonZeroI8<const VAL: i8> = RangedNonZeroI8<VAL, VAL>;
@@ -38,6 +42,8 @@ pub type UnitNonZeroI32<const VAL: i32> = RangedNonZeroI32<VAL, VAL>;
 pub type UnitNonZeroI64<const VAL: i64> = RangedNonZeroI64<VAL, VAL>;
 /// [`i128`] that's guaranteed to be a specific non-zero value
 pub type UnitNonZeroI128<const VAL: i128> = RangedNonZeroI128<VAL, VAL>;
+/// [`isize`] that's guaranteed to be a specific non-zero value
+pub type UnitNonZeroIsize<const VAL: isize> = RangedNonZeroIsize<VAL, VAL>;
 
 /// [`u8`] that's guaranteed to be a specific non-zero value
 pub type UnitNonZeroU8<const VAL: i8> = RangedNonZeroU8<VAL, VAL>;
@@ -49,6 +55,8 @@ pub type UnitNonZeroU32<const VAL: i32> = RangedNonZeroU32<VAL, VAL>;
 pub type UnitNonZeroU64<const VAL: i64> = RangedNonZeroU64<VAL, VAL>;
 /// [`u128`] that's guaranteed to be a specific non-zero value
 pub type UnitNonZeroU128<const VAL: i128> = RangedNonZeroU128<VAL, VAL>;
+/// [`usize`] that's guaranteed to be a specific non-zero value
+pub type UnitNonZeroUsize<const VAL: usize> = RangedNonZeroUsize<VAL, VAL>;
 
 macro_rules! impl_default {
     ($type:ident, $p:ty $(,)?) => {
@@ -88,21 +96,25 @@ impl_default!(UnitI16, i16);
 impl_default!(UnitI32, i32);
 impl_default!(UnitI64, i64);
 impl_default!(UnitI128, i128);
+impl_default!(UnitIsize, isize);
 
 impl_default!(UnitU8, u8);
 impl_default!(UnitU16, u16);
 impl_default!(UnitU32, u32);
 impl_default!(UnitU64, u64);
 impl_default!(UnitU128, u128);
+impl_default!(UnitUsize, usize);
 
 impl_default!(UnitNonZeroI8, i8);
 impl_default!(UnitNonZeroI16, i16);
 impl_default!(UnitNonZeroI32, i32);
 impl_default!(UnitNonZeroI64, i64);
 impl_default!(UnitNonZeroI128, i128);
+impl_default!(UnitNonZeroIsize, isize);
 
 impl_default!(UnitNonZeroU8, u8);
 impl_default!(UnitNonZeroU16, u16);
 impl_default!(UnitNonZeroU32, u32);
 impl_default!(UnitNonZeroU64, u64);
 impl_default!(UnitNonZeroU128, u128);
+impl_default!(UnitNonZeroUsize, usize);
@@ -0,0 +1,260 @@
+use crate::{
+    RangedI8, RangedI16, RangedI32, RangedI64, RangedI128, RangedU8, RangedU16,
+    RangedU32, RangedU64, RangedU128, bounds,
+};
+
+macro_rules! widening {
+    ($type:ident, $p:ty, $wide:ident, $wp:ident) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            #[doc = concat!("Add two numbers together, widening to [`", stringify!($wide), "`].")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<100>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<0, 100>::new::<50>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 200> = a.widening_add_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 150);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn widening_add_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let (b_min, b_max) = (RHS_MIN as $wp, RHS_MAX as $wp);
+                    let min = bounds::$wp::add_min(a_min, a_max, b_min, b_max);
+                    let max = bounds::$wp::add_max(a_min, a_max, b_min, b_max);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp + rhs.get() as $wp)
+            }
+
+            #[doc = concat!("Subtract a number from this one, widening to [`", stringify!($wide), "`].")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<100, 120>::new::<120>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<0, 100>::new::<100>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 120> = a.widening_sub_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 20);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn widening_sub_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let (b_min, b_max) = (RHS_MIN as $wp, RHS_MAX as $wp);
+                    let min = bounds::$wp::sub_min(a_min, a_max, b_min, b_max);
+                    let max = bounds::$wp::sub_max(a_min, a_max, b_min, b_max);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp - rhs.get() as $wp)
+            }
+
+            #[doc = concat!("Multiply two numbers together, widening to [`", stringify!($wide), "`].")]
+            ///
+            /// Unlike [`mul_ranged`](Self::mul_ranged), the product always
+            /// fits.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<100>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<1, 100>::new::<20>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 10000> = a.widening_mul_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 2000);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn widening_mul_ranged<
+                const RHS_MIN: $p,
+                const RHS_MAX: $p,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $type<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let (b_min, b_max) = (RHS_MIN as $wp, RHS_MAX as $wp);
+                    let min = bounds::$wp::mul_min(a_min, a_max, b_min, b_max);
+                    let max = bounds::$wp::mul_max(a_min, a_max, b_min, b_max);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp * rhs.get() as $wp)
+            }
+
+            #[doc = concat!("Add a [`", stringify!($wide), "`] to this number.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<100>();")]
+            #[doc = concat!("let b = ", stringify!($wide), "::<0, 1000>::new::<1000>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 1100> = a.add_wide_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 1100);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn add_wide_ranged<
+                const RHS_MIN: $wp,
+                const RHS_MAX: $wp,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $wide<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let min =
+                        bounds::$wp::add_min(a_min, a_max, RHS_MIN, RHS_MAX);
+                    let max =
+                        bounds::$wp::add_max(a_min, a_max, RHS_MIN, RHS_MAX);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp + rhs.get())
+            }
+
+            #[doc = concat!("Subtract a [`", stringify!($wide), "`] from this number.")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<100, 100>::new::<100>();")]
+            #[doc = concat!("let b = ", stringify!($wide), "::<0, 100>::new::<30>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 100> = a.sub_wide_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 70);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn sub_wide_ranged<
+                const RHS_MIN: $wp,
+                const RHS_MAX: $wp,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $wide<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let min =
+                        bounds::$wp::sub_min(a_min, a_max, RHS_MIN, RHS_MAX);
+                    let max =
+                        bounds::$wp::sub_max(a_min, a_max, RHS_MIN, RHS_MAX);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp - rhs.get())
+            }
+
+            #[doc = concat!("Multiply this number by a [`", stringify!($wide), "`].")]
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", ", stringify!($wide), "};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<0, 100>::new::<100>();")]
+            #[doc = concat!("let b = ", stringify!($wide), "::<1, 300>::new::<300>();")]
+            #[doc = concat!("let output: ", stringify!($wide), "<0, 30000> = a.mul_wide_ranged(b);")]
+            ///
+            /// assert_eq!(output.get(), 30000);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn mul_wide_ranged<
+                const RHS_MIN: $wp,
+                const RHS_MAX: $wp,
+                const OUTPUT_MIN: $wp,
+                const OUTPUT_MAX: $wp,
+            >(
+                self,
+                rhs: $wide<RHS_MIN, RHS_MAX>,
+            ) -> $wide<OUTPUT_MIN, OUTPUT_MAX> {
+                const {
+                    let (a_min, a_max) = (MIN as $wp, MAX as $wp);
+                    let min =
+                        bounds::$wp::mul_min(a_min, a_max, RHS_MIN, RHS_MAX);
+                    let max =
+                        bounds::$wp::mul_max(a_min, a_max, RHS_MIN, RHS_MAX);
+
+                    if min != OUTPUT_MIN {
+                        panic!("Min mismatch");
+                    }
+
+                    if max != OUTPUT_MAX {
+                        panic!("Max mismatch");
+                    }
+                }
+
+                $wide(self.get() as $wp * rhs.get())
+            }
+        }
+    };
+}
+
+widening!(RangedU8, u8, RangedU16, u16);
+widening!(RangedU16, u16, RangedU32, u32);
+widening!(RangedU32, u32, RangedU64, u64);
+widening!(RangedU64, u64, RangedU128, u128);
+
+widening!(RangedI8, i8, RangedI16, i16);
+widening!(RangedI16, i16, RangedI32, i32);
+widening!(RangedI32, i32, RangedI64, i64);
+widening!(RangedI64, i64, RangedI128, i128);
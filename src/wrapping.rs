@@ -0,0 +1,180 @@
+use core::{
+    num::NonZero,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign,
+        Sub, SubAssign,
+    },
+};
+
+use as_repr::AsRepr;
+
+use super::*;
+
+/// Intentionally wrapped arithmetic on ranged integers
+///
+/// Operations like `+` on [`Wrapping`] values wrap around within the range of
+/// the contained type, rather than panicking when out of range.  This mirrors
+/// [`core::num::Wrapping`].
+///
+/// ```rust
+/// # use ranch::{RangedU8, Wrapping};
+/// let mut hour = Wrapping(RangedU8::<1, 12>::new::<11>());
+///
+/// hour += 1;
+/// assert_eq!(hour, Wrapping(RangedU8::new::<12>()));
+/// hour += 1;
+/// assert_eq!(hour, Wrapping(RangedU8::new::<1>()));
+/// assert_eq!(hour - hour - 1, Wrapping(RangedU8::new::<11>()));
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+macro_rules! impl_wrapping {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> Wrapping<$type<MIN, MAX>> {
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self($type::MAX);
+            /// The smallest value that can be represented by this integer
+            /// type.
+            pub const MIN: Self = Self($type::MIN);
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Add<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn add(self, other: T) -> Self {
+                Self(self.0.wrapping_add(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Sub<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn sub(self, other: T) -> Self {
+                Self(self.0.wrapping_sub(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Mul<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn mul(self, other: T) -> Self {
+                Self(self.0.wrapping_mul(other))
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Div<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            type Output = Self;
+
+            fn div(self, other: T) -> Self {
+                Self(
+                    self.0
+                        .wrapping_div(other)
+                        .number()
+                        .expect("cannot divide by zero"),
+                )
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> Rem<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<NonZero<$p>>,
+        {
+            type Output = Self;
+
+            fn rem(self, other: T) -> Self {
+                Self(self.0.wrapping_rem(other))
+            }
+        }
+
+        impl<const MIN: $p, const MAX: $p> Neg for Wrapping<$type<MIN, MAX>> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> AddAssign<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn add_assign(&mut self, other: T) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> SubAssign<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn sub_assign(&mut self, other: T) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> MulAssign<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn mul_assign(&mut self, other: T) {
+                *self = *self * other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> DivAssign<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<$p>,
+        {
+            fn div_assign(&mut self, other: T) {
+                *self = *self / other;
+            }
+        }
+
+        impl<T, const MIN: $p, const MAX: $p> RemAssign<T>
+            for Wrapping<$type<MIN, MAX>>
+        where
+            T: AsRepr<NonZero<$p>>,
+        {
+            fn rem_assign(&mut self, other: T) {
+                *self = *self % other;
+            }
+        }
+    };
+}
+
+impl_wrapping!(RangedI8, i8);
+impl_wrapping!(RangedI16, i16);
+impl_wrapping!(RangedI32, i32);
+impl_wrapping!(RangedI64, i64);
+impl_wrapping!(RangedI128, i128);
+impl_wrapping!(RangedIsize, isize);
+
+impl_wrapping!(RangedU8, u8);
+impl_wrapping!(RangedU16, u16);
+impl_wrapping!(RangedU32, u32);
+impl_wrapping!(RangedU64, u64);
+impl_wrapping!(RangedU128, u128);
+impl_wrapping!(RangedUsize, usize);
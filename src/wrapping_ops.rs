@@ -0,0 +1,499 @@
+use core::num::NonZero;
+
+use as_repr::AsRepr;
+
+use super::*;
+
+macro_rules! impl_wrapping {
+    ($type:ident, $p:ty, $u:ty, $add_unsigned:ident $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Add a number to `self`, wrapping around within the range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<12>();")]
+            ///
+            /// assert_eq!(a.wrapping_add(1).get(), 1);
+            /// assert_eq!(a.wrapping_add(14).get(), 2);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_add(self, other: impl AsRepr<$p>) -> Self {
+                self.overflowing_add(other).0
+            }
+
+            /// Subtract a number from `self`, wrapping around within the
+            /// range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<1>();")]
+            ///
+            /// assert_eq!(a.wrapping_sub(1).get(), 12);
+            /// assert_eq!(a.wrapping_sub(14).get(), 11);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_sub(self, other: impl AsRepr<$p>) -> Self {
+                self.overflowing_sub(other).0
+            }
+
+            /// Multiply `self` by a number, wrapping around within the range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<5>();")]
+            ///
+            /// assert_eq!(a.wrapping_mul(2).get(), 10);
+            /// assert_eq!(a.wrapping_mul(5).get(), 1);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_mul(self, other: impl AsRepr<$p>) -> Self {
+                self.overflowing_mul(other).0
+            }
+
+            /// Negate `self`, wrapping around within the range.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<5>();")]
+            ///
+            /// assert_eq!(a.wrapping_neg().get(), 7);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_neg(self) -> Self {
+                self.overflowing_neg().0
+            }
+
+            /// Divide `self` by a number, wrapping around within the range.
+            ///
+            /// Returns [`Quotient::Nan`] if `rhs` is 0.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::{", stringify!($type), ", Quotient};")]
+            #[doc = concat!("let a = ", stringify!($type), "::<5, 12>::new::<12>();")]
+            ///
+            /// assert_eq!(a.wrapping_div(2).number().unwrap().get(), 6);
+            /// assert_eq!(a.wrapping_div(3).number().unwrap().get(), 12);
+            /// assert_eq!(a.wrapping_div(0), Quotient::Nan);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_div(
+                self,
+                rhs: impl AsRepr<$p>,
+            ) -> Quotient<Self> {
+                let rhs = as_repr::as_repr(rhs);
+
+                if rhs == 0 {
+                    return Quotient::Nan;
+                }
+
+                Quotient::Number(match self.get().checked_div(rhs) {
+                    Some(value) => Self::wrap(value),
+                    // `MIN / -1` is one more than the primitive's `MAX`
+                    None => Self::wrap(<$p>::MAX).wrapping_add(1),
+                })
+            }
+
+            /// Calculate the remainder of `self` divided by a non-zero number,
+            /// wrapping around within the range.
+            ///
+            /// ```rust
+            /// # use core::num::NonZero;
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<5, 12>::new::<12>();")]
+            #[doc = concat!("let b = NonZero::<", stringify!($p), ">::new(5).unwrap();")]
+            ///
+            /// assert_eq!(a.wrapping_rem(b).get(), 10);
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn wrapping_rem(
+                self,
+                rhs: impl AsRepr<NonZero<$p>>,
+            ) -> Self {
+                let rhs = as_repr::as_repr(rhs);
+
+                Self::wrap(self.get().wrapping_rem(rhs.get()))
+            }
+
+            /// Multiply `self` by a number, wrapping around within the range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped, which is negative when wrapping past `MIN` (wrapping
+            /// around for unsigned types), as is the count if it doesn't fit.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<5>();")]
+            ///
+            /// assert_eq!(a.overflowing_mul(2), (a.wrapping_mul(2), 0));
+            /// assert_eq!(a.overflowing_mul(5), (a.wrapping_mul(5), 2));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            // casts are only trivial for unsigned numbers
+            #[allow(trivial_numeric_casts)]
+            pub const fn overflowing_mul(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> (Self, $p) {
+                let other = as_repr::as_repr(other);
+                let (lhs_quotient, lhs) = Self::div_rem(self.get());
+                let (rhs_quotient, rhs) = Self::div_rem(other);
+                let (min_quotient, min) = Self::div_rem(MIN);
+                let (quotient, product) = Self::mul_div_rem(lhs, rhs);
+                // `lhs * rhs / width`, expanding each operand into its
+                // quotient and residue
+                let quotient = lhs_quotient
+                    .wrapping_mul(rhs_quotient)
+                    .wrapping_mul(Self::width())
+                    .wrapping_add(lhs_quotient.wrapping_mul(rhs))
+                    .wrapping_add(rhs_quotient.wrapping_mul(lhs))
+                    .wrapping_add(quotient);
+                let (offset, borrow) = if product >= min {
+                    (product - min, 0)
+                } else {
+                    (product.wrapping_add(Self::width().wrapping_sub(min)), 1)
+                };
+                let count =
+                    quotient.wrapping_sub(min_quotient).wrapping_sub(borrow);
+
+                (Self::from_offset(offset), count as $p)
+            }
+
+            /// Difference between `MAX` and `MIN`, one less than the number of
+            /// values in the range.
+            const fn span() -> $u {
+                MAX.abs_diff(MIN)
+            }
+
+            /// Number of values in the range, zero if it's every value.
+            const fn width() -> $u {
+                Self::span().wrapping_add(1)
+            }
+
+            const fn offset(self) -> $u {
+                self.get().abs_diff(MIN)
+            }
+
+            const fn from_offset(offset: $u) -> Self {
+                $type(MIN.$add_unsigned(offset))
+            }
+
+            /// Wrap any `value` around into the range.
+            const fn wrap(value: $p) -> Self {
+                Self::MIN.wrapping_add(value).wrapping_sub(MIN)
+            }
+
+            /// Get the floored quotient (wrapping around) and remainder of
+            /// `value` divided by the number of values in the range.
+            // `value` comparison only needed for signed numbers
+            #[allow(unused_comparisons)]
+            const fn div_rem(value: $p) -> ($u, $u) {
+                let width = Self::width();
+                let magnitude = value.abs_diff(0);
+                let (quotient, residue) = if width == 0 {
+                    (0, magnitude)
+                } else {
+                    (magnitude / width, magnitude % width)
+                };
+
+                if value >= 0 {
+                    (quotient, residue)
+                } else if residue == 0 {
+                    (quotient.wrapping_neg(), 0)
+                } else {
+                    (
+                        quotient.wrapping_add(1).wrapping_neg(),
+                        width.wrapping_sub(residue),
+                    )
+                }
+            }
+
+            /// Multiply two residues, returning the quotient (wrapping around)
+            /// and remainder of the product divided by the number of values in
+            /// the range.
+            const fn mul_div_rem(mut lhs: $u, mut rhs: $u) -> ($u, $u) {
+                let (mut quotient, mut product): ($u, $u) = (0, 0);
+                // quotient of `lhs` as it's doubled
+                let mut lhs_quotient: $u = 0;
+
+                while rhs != 0 {
+                    if rhs & 1 == 1 {
+                        let (sum, carry) = Self::add_rem(product, lhs);
+
+                        product = sum;
+                        quotient = quotient
+                            .wrapping_add(lhs_quotient)
+                            .wrapping_add(carry as $u);
+                    }
+
+                    let (double, carry) = Self::add_rem(lhs, lhs);
+
+                    lhs = double;
+                    lhs_quotient = lhs_quotient
+                        .wrapping_mul(2)
+                        .wrapping_add(carry as $u);
+                    rhs >>= 1;
+                }
+
+                (quotient, product)
+            }
+
+            /// Add two residues, returning the sum modulo the number of values
+            /// in the range and whether it wrapped.
+            const fn add_rem(lhs: $u, rhs: $u) -> ($u, bool) {
+                let width = Self::width();
+
+                if width == 0 {
+                    lhs.overflowing_add(rhs)
+                } else if lhs >= width - rhs {
+                    (lhs - (width - rhs), true)
+                } else {
+                    (lhs + rhs, false)
+                }
+            }
+
+            /// Move an offset up by `amount`, returning the new offset and the
+            /// number of times it wrapped past `MAX`.
+            const fn wrap_up(offset: $u, amount: $u) -> ($u, $u) {
+                if Self::span() == <$u>::MAX {
+                    let (offset, wrapped) = offset.overflowing_add(amount);
+
+                    return (offset, wrapped as $u);
+                }
+
+                let width = Self::span() + 1;
+                let (count, amount) = (amount / width, amount % width);
+
+                if amount > Self::span() - offset {
+                    (amount - (Self::span() - offset) - 1, count + 1)
+                } else {
+                    (offset + amount, count)
+                }
+            }
+
+            /// Move an offset down by `amount`, returning the new offset and
+            /// the number of times it wrapped past `MIN`.
+            const fn wrap_down(offset: $u, amount: $u) -> ($u, $u) {
+                if Self::span() == <$u>::MAX {
+                    let (offset, wrapped) = offset.overflowing_sub(amount);
+
+                    return (offset, wrapped as $u);
+                }
+
+                let width = Self::span() + 1;
+                let (count, amount) = (amount / width, amount % width);
+
+                if amount > offset {
+                    (width - (amount - offset), count + 1)
+                } else {
+                    (offset - amount, count)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapping_signed {
+    ($type:ident, $p:ty, $u:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Add a number to `self`, wrapping around within the range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped, which is negative when wrapping past `MIN`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<12>();")]
+            ///
+            /// assert_eq!(a.overflowing_add(25), (a.wrapping_add(25), 3));
+            /// assert_eq!(a.overflowing_add(-12), (a.wrapping_add(-12), -1));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_add(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> (Self, $p) {
+                let other = as_repr::as_repr(other);
+
+                Self::shift(self.offset(), other < 0, other.unsigned_abs())
+            }
+
+            /// Subtract a number from `self`, wrapping around within the
+            /// range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped, which is negative when wrapping past `MIN`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<1>();")]
+            ///
+            /// assert_eq!(a.overflowing_sub(25), (a.wrapping_sub(25), -3));
+            /// assert_eq!(a.overflowing_sub(-12), (a.wrapping_sub(-12), 1));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_sub(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> (Self, $p) {
+                let other = as_repr::as_repr(other);
+
+                Self::shift(self.offset(), other >= 0, other.unsigned_abs())
+            }
+
+            /// Negate `self`, wrapping around within the range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped, which is negative when wrapping past `MIN`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<5>();")]
+            #[doc = concat!("let b = ", stringify!($type), "::<-4, 4>::new::<-4>();")]
+            ///
+            /// assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), -1));
+            #[doc = concat!("assert_eq!(b.overflowing_neg(), (", stringify!($type), "::new::<4>(), 0));")]
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_neg(self) -> (Self, $p) {
+                // `-value - MIN` from `MIN`
+                let value = self.get();
+                let (value, first) =
+                    Self::shift(0, value >= 0, value.unsigned_abs());
+                let (value, second) =
+                    Self::shift(value.offset(), MIN >= 0, MIN.unsigned_abs());
+
+                (value, first.wrapping_add(second))
+            }
+
+            const fn shift(
+                offset: $u,
+                down: bool,
+                amount: $u,
+            ) -> (Self, $p) {
+                if down {
+                    let (offset, count) = Self::wrap_down(offset, amount);
+
+                    (Self::from_offset(offset), (count as $p).wrapping_neg())
+                } else {
+                    let (offset, count) = Self::wrap_up(offset, amount);
+
+                    (Self::from_offset(offset), count as $p)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapping_unsigned {
+    ($type:ident, $p:ty $(,)?) => {
+        impl<const MIN: $p, const MAX: $p> $type<MIN, MAX> {
+            /// Add a number to `self`, wrapping around within the range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped past `MAX`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<12>();")]
+            ///
+            /// assert_eq!(a.overflowing_add(1), (a.wrapping_add(1), 1));
+            /// assert_eq!(a.overflowing_add(25), (a.wrapping_add(25), 3));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_add(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> (Self, $p) {
+                let other = as_repr::as_repr(other);
+                let (offset, count) = Self::wrap_up(self.offset(), other);
+
+                (Self::from_offset(offset), count)
+            }
+
+            /// Subtract a number from `self`, wrapping around within the
+            /// range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped past `MIN`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<1>();")]
+            ///
+            /// assert_eq!(a.overflowing_sub(1), (a.wrapping_sub(1), 1));
+            /// assert_eq!(a.overflowing_sub(25), (a.wrapping_sub(25), 3));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_sub(
+                self,
+                other: impl AsRepr<$p>,
+            ) -> (Self, $p) {
+                let other = as_repr::as_repr(other);
+                let (offset, count) = Self::wrap_down(self.offset(), other);
+
+                (Self::from_offset(offset), count)
+            }
+
+            /// Negate `self`, wrapping around within the range.
+            ///
+            /// Returns a tuple of the result along with the number of times it
+            /// wrapped past `MIN`.
+            ///
+            /// ```rust
+            #[doc = concat!("# use ranch::", stringify!($type), ";")]
+            #[doc = concat!("let a = ", stringify!($type), "::<1, 12>::new::<5>();")]
+            ///
+            /// assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), 1));
+            /// ```
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            pub const fn overflowing_neg(self) -> (Self, $p) {
+                // `-value - MIN` from `MIN`
+                let (offset, first) = Self::wrap_down(0, self.get());
+                let (offset, second) = Self::wrap_down(offset, MIN);
+
+                (Self::from_offset(offset), first.wrapping_add(second))
+            }
+        }
+    };
+}
+
+impl_wrapping!(RangedI8, i8, u8, wrapping_add_unsigned);
+impl_wrapping!(RangedI16, i16, u16, wrapping_add_unsigned);
+impl_wrapping!(RangedI32, i32, u32, wrapping_add_unsigned);
+impl_wrapping!(RangedI64, i64, u64, wrapping_add_unsigned);
+impl_wrapping!(RangedI128, i128, u128, wrapping_add_unsigned);
+impl_wrapping!(RangedIsize, isize, usize, wrapping_add_unsigned);
+
+impl_wrapping!(RangedU8, u8, u8, wrapping_add);
+impl_wrapping!(RangedU16, u16, u16, wrapping_add);
+impl_wrapping!(RangedU32, u32, u32, wrapping_add);
+impl_wrapping!(RangedU64, u64, u64, wrapping_add);
+impl_wrapping!(RangedU128, u128, u128, wrapping_add);
+impl_wrapping!(RangedUsize, usize, usize, wrapping_add);
+
+impl_wrapping_signed!(RangedI8, i8, u8);
+impl_wrapping_signed!(RangedI16, i16, u16);
+impl_wrapping_signed!(RangedI32, i32, u32);
+impl_wrapping_signed!(RangedI64, i64, u64);
+impl_wrapping_signed!(RangedI128, i128, u128);
+impl_wrapping_signed!(RangedIsize, isize, usize);
+
+impl_wrapping_unsigned!(RangedU8, u8);
+impl_wrapping_unsigned!(RangedU16, u16);
+impl_wrapping_unsigned!(RangedU32, u32);
+impl_wrapping_unsigned!(RangedU64, u64);
+impl_wrapping_unsigned!(RangedU128, u128);
+impl_wrapping_unsigned!(RangedUsize, usize);
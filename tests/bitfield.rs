@@ -0,0 +1,42 @@
+use ranch::{
+    bitfield,
+    bitwise::{I7, NonZeroI56, U1, U64},
+    range::Error,
+};
+
+bitfield! {
+    struct Register(u128) {
+        low, set_low: I7,
+        flag, set_flag: U1,
+        wide, set_wide: U64,
+        high, set_high: NonZeroI56,
+    }
+}
+
+#[test]
+fn bitfield() {
+    let mut register =
+        Register::new(I7::MIN, U1::new::<1>(), U64::MAX, NonZeroI56::MIN);
+
+    assert_eq!(Register::WIDTH, 128);
+    assert_eq!(
+        register.to_bits(),
+        0x8000_0000_0000_00FF_FFFF_FFFF_FFFF_FFC0
+    );
+    assert_eq!(register.low(), I7::MIN);
+    assert_eq!(register.high(), NonZeroI56::MIN);
+
+    register.set_low(I7::new::<-1>());
+    register.set_wide(U64::new::<0>());
+    register.set_high(NonZeroI56::new::<1>());
+    assert_eq!(
+        register.to_bits(),
+        0x0000_0000_0000_0100_0000_0000_0000_00FF
+    );
+    assert_eq!(Register::from_bits(register.to_bits()), Ok(register));
+    assert_eq!(Register::from_bits(0xFF), Err(Error::Zero));
+    assert_eq!(
+        format!("{register:?}"),
+        "Register { low: -1, flag: 1, wide: 0, high: 1 }",
+    );
+}
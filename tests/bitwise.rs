@@ -298,3 +298,62 @@ fn bitwise_sizes() {
     assert_eq!(I127::BITS, 128);
     assert_eq!(I128::BITS, 128);
 }
+
+#[test]
+fn bitwise_ops() {
+    let a = U4::new::<13>();
+
+    assert_eq!(a.and::<0b11, _, _>(), U2::new::<1>());
+    assert_eq!(a.shr::<2, _, _>(), U2::new::<3>());
+    assert_eq!(U2::new::<3>().shl::<2, 0, 12>().get(), 12);
+    assert_eq!(a & 0b0110, U4::new::<4>());
+    assert_eq!(a | 0b0010, U4::new::<15>());
+    assert_eq!(a ^ 0b1111, U4::new::<2>());
+    assert_eq!(a.checked_shl(1), None);
+    assert_eq!(a.saturating_shl(1), U4::MAX);
+
+    let b = I4::new::<-3>();
+
+    let output: ranch::RangedI8<0, 3> =
+        b.and_ranged(I3::new::<3>().and::<3, 0, 3>());
+    assert_eq!(output.get(), 1);
+    assert_eq!(b.checked_not().unwrap().get(), 2);
+    assert_eq!(b.checked_shl(2), Err(ranch::Error::NegOverflow));
+    assert_eq!(b.saturating_shl(2), I4::MIN);
+    assert_eq!(b >> 1, I4::new::<-2>());
+    assert_eq!(b >> 8, I4::new::<-1>());
+
+    let mut c = U4::new::<1>();
+
+    c <<= 3;
+    assert_eq!(c.get(), 8);
+    c |= 1;
+    c ^= 0b11;
+    c &= 0b1110;
+    assert_eq!(c.get(), 10);
+    c >>= 2;
+    assert_eq!(c.get(), 2);
+}
+
+#[test]
+fn bitwise_signed_bounds() {
+    let a = ranch::RangedI8::<-8, 7>::new::<-5>();
+    let b = ranch::RangedI8::<0, 3>::new::<3>();
+    let c = ranch::RangedI8::<-4, -1>::new::<-4>();
+    let d = ranch::RangedI8::<-3, 2>::new::<-1>();
+    let e = ranch::RangedI8::<1, 5>::new::<5>();
+    let f = ranch::RangedI8::<-5, -2>::new::<-2>();
+    let g = ranch::RangedI8::<-7, 6>::new::<6>();
+
+    let output: ranch::RangedI8<0, 3> = a.and_ranged(b);
+    assert_eq!(output.get(), 3);
+
+    let output: ranch::RangedI8<-4, -1> = c.or_ranged(b);
+    assert_eq!(output.get(), -1);
+
+    let output: ranch::RangedI8<-8, 7> = d.xor_ranged(e);
+    assert_eq!(output.get(), -6);
+
+    let output: ranch::RangedI8<-8, 6> = f.and_ranged(g);
+    assert_eq!(output.get(), 6);
+}
@@ -0,0 +1,343 @@
+use std::{
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+use ranch::{Quotient, RangedI8, RangedU8, RangedU32, bounds};
+
+type Bounds<T> = fn(T, T, T, T) -> T;
+type Clamp<T> = fn(T, T, T, T, T, T) -> T;
+
+/// Every non-empty interval within `values`
+fn intervals(values: &[i32]) -> Vec<(i32, i32)> {
+    values
+        .iter()
+        .flat_map(|&min| {
+            values
+                .iter()
+                .filter(move |&&max| min <= max)
+                .map(move |&max| (min, max))
+        })
+        .collect()
+}
+
+/// Tight bounds of every output, or `None` if an output doesn't fit or there
+/// are no outputs
+fn expected(
+    outputs: impl IntoIterator<Item = Option<i32>>,
+    fits: (i32, i32),
+) -> Option<(i32, i32)> {
+    let mut bounds = None;
+
+    for output in outputs.into_iter().flatten() {
+        if output < fits.0 || output > fits.1 {
+            return None;
+        }
+
+        bounds = match bounds {
+            None => Some((output, output)),
+            Some((min, max)) => Some((output.min(min), output.max(max))),
+        };
+    }
+
+    bounds
+}
+
+/// Bounds calculated by `f`, or `None` if it panics
+fn actual(f: impl FnOnce() -> (i32, i32)) -> Option<(i32, i32)> {
+    panic::catch_unwind(AssertUnwindSafe(f)).ok()
+}
+
+/// Assert that `outputs` reach both ends of `min..=max`
+fn assert_spans<T: Copy + Debug + Ord>(outputs: &[T], min: T, max: T) {
+    assert_eq!(outputs.iter().min(), Some(&min));
+    assert_eq!(outputs.iter().max(), Some(&max));
+}
+
+macro_rules! exhaustive {
+    ($name:ident, $p:ident, $values:expr) => {
+        #[test]
+        fn $name() {
+            let values: Vec<i32> = $values.collect();
+            let intervals = intervals(&values);
+            let fits = (i32::from($p::MIN), i32::from($p::MAX));
+            let binary: [(Bounds<$p>, Bounds<$p>, fn(i32, i32) -> Option<i32>);
+                4] = [
+                (bounds::$p::add_min, bounds::$p::add_max, |a, b| Some(a + b)),
+                (bounds::$p::sub_min, bounds::$p::sub_max, |a, b| Some(a - b)),
+                (bounds::$p::mul_min, bounds::$p::mul_max, |a, b| Some(a * b)),
+                (bounds::$p::div_min, bounds::$p::div_max, |a, b| {
+                    (b != 0).then(|| a / b)
+                }),
+            ];
+            let ordering: [(Bounds<$p>, Bounds<$p>, fn(i32, i32) -> i32); 2] = [
+                (bounds::$p::min_min, bounds::$p::min_max, i32::min),
+                (bounds::$p::max_min, bounds::$p::max_max, i32::max),
+            ];
+            let narrow = |x: i32| $p::try_from(x).unwrap();
+
+            for &(a_min, a_max) in &intervals {
+                let a = a_min..=a_max;
+
+                for &(b_min, b_max) in &intervals {
+                    let b = b_min..=b_max;
+                    let args = (
+                        narrow(a_min),
+                        narrow(a_max),
+                        narrow(b_min),
+                        narrow(b_max),
+                    );
+
+                    for (min, max, op) in binary {
+                        let outputs = a
+                            .clone()
+                            .flat_map(|x| b.clone().map(move |y| op(x, y)));
+                        let bounds = actual(|| {
+                            (
+                                min(args.0, args.1, args.2, args.3).into(),
+                                max(args.0, args.1, args.2, args.3).into(),
+                            )
+                        });
+
+                        assert_eq!(
+                            bounds,
+                            expected(outputs, fits),
+                            "{a:?} and {b:?}",
+                        );
+                    }
+
+                    for (min, max, op) in ordering {
+                        let outputs = a.clone().flat_map(|x| {
+                            b.clone().map(move |y| Some(op(x, y)))
+                        });
+                        let bounds = (
+                            min(args.0, args.1, args.2, args.3).into(),
+                            max(args.0, args.1, args.2, args.3).into(),
+                        );
+
+                        assert_eq!(
+                            Some(bounds),
+                            expected(outputs, fits),
+                            "{a:?} and {b:?}",
+                        );
+                    }
+                }
+
+                for exp_min in 0..=8 {
+                    for exp_max in exp_min..=8 {
+                        let outputs = a.clone().flat_map(|x| {
+                            (exp_min..=exp_max)
+                                .map(move |exp| x.checked_pow(exp))
+                        });
+                        let bounds = actual(|| {
+                            let (a_min, a_max) = (narrow(a_min), narrow(a_max));
+
+                            (
+                                bounds::$p::pow_min(
+                                    a_min, a_max, exp_min, exp_max,
+                                )
+                                .into(),
+                                bounds::$p::pow_max(
+                                    a_min, a_max, exp_min, exp_max,
+                                )
+                                .into(),
+                            )
+                        });
+
+                        assert_eq!(
+                            bounds,
+                            expected(outputs, fits),
+                            "{a:?} to {exp_min}..={exp_max}",
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! exhaustive_clamp {
+    ($name:ident, $p:ident, $values:expr) => {
+        #[test]
+        fn $name() {
+            let values: Vec<i32> = $values.collect();
+            let intervals = intervals(&values);
+            let fits = (i32::from($p::MIN), i32::from($p::MAX));
+            let (min, max): (Clamp<$p>, Clamp<$p>) =
+                (bounds::$p::clamp_min, bounds::$p::clamp_max);
+            let narrow = |x: i32| $p::try_from(x).unwrap();
+
+            for &(a_min, a_max) in &intervals {
+                for &(lo_min, lo_max) in &intervals {
+                    for &(hi_min, hi_max) in &intervals {
+                        let outputs = (a_min..=a_max).flat_map(|x| {
+                            (lo_min..=lo_max).flat_map(move |lo| {
+                                (hi_min..=hi_max).map(move |hi| {
+                                    (lo <= hi).then(|| x.clamp(lo, hi))
+                                })
+                            })
+                        });
+                        let args =
+                            [a_min, a_max, lo_min, lo_max, hi_min, hi_max]
+                                .map(narrow);
+                        let bounds = actual(|| {
+                            let [a, b, c, d, e, f] = args;
+
+                            (
+                                min(a, b, c, d, e, f).into(),
+                                max(a, b, c, d, e, f).into(),
+                            )
+                        });
+
+                        assert_eq!(
+                            bounds,
+                            expected(outputs, fits),
+                            "{:?}",
+                            args,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! exhaustive_ranged {
+    (
+        $name:ident, $type:ident, $p:ident,
+        $lhs:expr, $rhs:expr, $upper:expr $(,)?
+    ) => {
+        #[test]
+        fn $name() {
+            const A: ($p, $p) = $lhs;
+            const B: ($p, $p) = $rhs;
+            const C: ($p, $p) = $upper;
+
+            type Lhs = $type<{ A.0 }, { A.1 }>;
+            type Rhs = $type<{ B.0 }, { B.1 }>;
+            type Upper = $type<{ C.0 }, { C.1 }>;
+            type Exp = RangedU32<0, 2>;
+            type Sum = $type<
+                { bounds::$p::add_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::add_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Difference = $type<
+                { bounds::$p::sub_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::sub_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Product = $type<
+                { bounds::$p::mul_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::mul_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Ratio = $type<
+                { bounds::$p::div_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::div_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Power = $type<
+                { bounds::$p::pow_min(A.0, A.1, 0, 2) },
+                { bounds::$p::pow_max(A.0, A.1, 0, 2) },
+            >;
+            type Minimum = $type<
+                { bounds::$p::min_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::min_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Maximum = $type<
+                { bounds::$p::max_min(A.0, A.1, B.0, B.1) },
+                { bounds::$p::max_max(A.0, A.1, B.0, B.1) },
+            >;
+            type Clamped = $type<
+                { bounds::$p::clamp_min(A.0, A.1, B.0, B.1, C.0, C.1) },
+                { bounds::$p::clamp_max(A.0, A.1, B.0, B.1, C.0, C.1) },
+            >;
+
+            let mut outputs: [Vec<$p>; 8] = Default::default();
+
+            for a in Lhs::all() {
+                for exp in Exp::all() {
+                    let power: Power = a.pow_ranged(exp);
+
+                    assert_eq!(power.get(), a.get().pow(exp.get()));
+                    outputs[4].push(power.get());
+                }
+
+                for b in Rhs::all() {
+                    let (x, y) = (a.get(), b.get());
+                    let sum: Sum = a.add_ranged(b);
+                    let difference: Difference = a.sub_ranged(b);
+                    let product: Product = a.mul_ranged(b);
+                    let ratio: Quotient<Ratio> = a.div_ranged(b);
+                    let minimum: Minimum = a.min_ranged(b);
+                    let maximum: Maximum = a.max_ranged(b);
+
+                    assert_eq!(sum.get(), x + y);
+                    assert_eq!(difference.get(), x - y);
+                    assert_eq!(product.get(), x * y);
+                    assert_eq!(minimum.get(), x.min(y));
+                    assert_eq!(maximum.get(), x.max(y));
+                    outputs[0].push(sum.get());
+                    outputs[1].push(difference.get());
+                    outputs[2].push(product.get());
+                    outputs[5].push(minimum.get());
+                    outputs[6].push(maximum.get());
+
+                    if let Some(ratio) = ratio.number() {
+                        assert_eq!(ratio.get(), x / y);
+                        outputs[3].push(ratio.get());
+                    } else {
+                        assert_eq!(y, 0);
+                    }
+
+                    for c in Upper::all().filter(|c| y <= c.get()) {
+                        let clamped: Clamped = a.clamp_ranged(b, c);
+
+                        assert_eq!(clamped.get(), x.clamp(y, c.get()));
+                        outputs[7].push(clamped.get());
+                    }
+                }
+            }
+
+            assert_spans(&outputs[0], Sum::MIN.get(), Sum::MAX.get());
+            assert_spans(
+                &outputs[1],
+                Difference::MIN.get(),
+                Difference::MAX.get(),
+            );
+            assert_spans(&outputs[2], Product::MIN.get(), Product::MAX.get());
+            assert_spans(&outputs[3], Ratio::MIN.get(), Ratio::MAX.get());
+            assert_spans(&outputs[4], Power::MIN.get(), Power::MAX.get());
+            assert_spans(&outputs[5], Minimum::MIN.get(), Minimum::MAX.get());
+            assert_spans(&outputs[6], Maximum::MIN.get(), Maximum::MAX.get());
+            assert_spans(&outputs[7], Clamped::MIN.get(), Clamped::MAX.get());
+        }
+    };
+}
+
+exhaustive!(exhaustive_i8, i8, -12..=12);
+exhaustive!(exhaustive_u8, u8, 0..=24);
+exhaustive_clamp!(exhaustive_clamp_i8, i8, -3..=3);
+exhaustive_clamp!(exhaustive_clamp_u8, u8, 0..=5);
+exhaustive_ranged!(ranged_i8_mixed, RangedI8, i8, (-5, 4), (-3, 2), (-1, 3));
+exhaustive_ranged!(
+    ranged_i8_negative,
+    RangedI8,
+    i8,
+    (-5, -2),
+    (-4, -1),
+    (-6, 0)
+);
+exhaustive_ranged!(ranged_i8_positive, RangedI8, i8, (1, 5), (0, 4), (2, 6));
+exhaustive_ranged!(ranged_u8_low, RangedU8, u8, (4, 9), (0, 3), (2, 6));
+exhaustive_ranged!(ranged_u8_high, RangedU8, u8, (5, 7), (2, 4), (0, 8));
+
+#[test]
+fn extremes() {
+    assert_eq!(bounds::i8::div_min(i8::MIN + 1, i8::MAX, -1, 1), -i8::MAX);
+    assert_eq!(bounds::i8::div_max(i8::MIN + 1, i8::MAX, -1, 1), i8::MAX);
+    assert!(
+        actual(|| (bounds::i8::div_max(i8::MIN, 0, -1, 1).into(), 0)).is_none()
+    );
+    assert_eq!(bounds::i8::mul_min(i8::MIN, i8::MAX, 0, 1), i8::MIN);
+    assert_eq!(bounds::i8::pow_min(i8::MIN, i8::MAX, 0, 1), i8::MIN);
+    assert_eq!(bounds::u8::div_max(0, u8::MAX, 0, u8::MAX), u8::MAX);
+    assert_eq!(bounds::u8::pow_max(0, 1, 0, u32::MAX), 1);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::checked::{self, CheckedCastError};
+use ranch::{RangedI16, RangedNonZeroI8, RangedU16, bitwise::U4};
+
+#[test]
+fn bytemuck() {
+    type Sample = RangedU16<0, 4095>;
+
+    let words = [0, 4095, 17, 2048];
+    let bytes: &[u8] = bytemuck::cast_slice(&words);
+    let samples: &[Sample] = checked::try_cast_slice(bytes).unwrap();
+
+    assert_eq!(samples.iter().map(|x| x.get()).collect::<Vec<_>>(), words);
+    assert_eq!(bytemuck::cast_slice::<Sample, u8>(samples), bytes);
+
+    let words = [0, 4096];
+    let bytes: &[u8] = bytemuck::cast_slice(&words);
+
+    assert_eq!(
+        checked::try_cast_slice::<u8, Sample>(bytes),
+        Err(CheckedCastError::InvalidBitPattern),
+    );
+    assert_eq!(
+        checked::try_cast::<u8, RangedNonZeroI8<-4, 4>>(0xFF),
+        Ok(RangedNonZeroI8::new::<-1>()),
+    );
+    assert_eq!(
+        checked::try_cast::<u8, RangedNonZeroI8<-4, 4>>(0),
+        Err(CheckedCastError::InvalidBitPattern),
+    );
+    assert_eq!(<U4 as bytemuck::Zeroable>::zeroed(), U4::MIN);
+    assert_eq!(
+        <RangedI16<0, 300> as bytemuck::Zeroable>::zeroed(),
+        RangedI16::MIN,
+    );
+}
@@ -0,0 +1,22 @@
+use ranch::{
+    Error,
+    bitwise::{I12, U12},
+};
+
+#[test]
+fn bytes() {
+    let value = U12::new::<0xABC>();
+
+    assert_eq!(value.to_be_bytes(), [0x0A, 0xBC]);
+    assert_eq!(value.to_le_bytes(), [0xBC, 0x0A]);
+    assert_eq!(U12::try_from_be_bytes([0x0A, 0xBC]), Ok(value));
+    assert_eq!(
+        U12::try_from_le_bytes([0x00, 0x10]),
+        Err(Error::PosOverflow)
+    );
+    assert_eq!(
+        I12::try_from_be_bytes([0xF7, 0xFF]),
+        Err(Error::NegOverflow)
+    );
+    assert_eq!(I12::try_from_be_bytes([0xF8, 0x00]), Ok(I12::MIN));
+}
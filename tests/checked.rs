@@ -0,0 +1,197 @@
+#![cfg(all(feature = "arbitrary", feature = "proptest"))]
+
+use core::ops::RangeInclusive;
+
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::{
+    prelude::*,
+    strategy::ValueTree,
+    test_runner::{Config, TestRunner},
+};
+use ranch::{
+    Error, Quotient, RangedI8, RangedI16, RangedI32, RangedI64, RangedI128,
+    RangedIsize, RangedNonZeroI16, RangedNonZeroU8, RangedU16, RangedU128,
+    range,
+};
+
+type Signed = RangedI8<-100, 100>;
+type Unsigned = RangedU16<1000, 60000>;
+type NonZero = RangedNonZeroI16<-300, 300>;
+type Wide = RangedU128<{ u128::MAX - 10 }, { u128::MAX }>;
+
+/// Expected result of a checked operation, computed without overflow
+fn expected(value: i128, range: RangeInclusive<i128>) -> range::Result<i128> {
+    if value < *range.start() {
+        Err(range::Error::NegOverflow)
+    } else if value > *range.end() {
+        Err(range::Error::PosOverflow)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Expected result of a checked operation on a non-zero type
+fn expected_nonzero(
+    value: i128,
+    range: RangeInclusive<i128>,
+) -> range::Result<i128> {
+    expected(value, range).and_then(|value| match value {
+        0 => Err(range::Error::Zero),
+        value => Ok(value),
+    })
+}
+
+proptest! {
+    #[test]
+    fn checked_signed(a: Signed, b: i8) {
+        let (a_wide, b_wide) = (i128::from(a.get()), i128::from(b));
+        let checked = |result: ranch::Result<Signed>| {
+            result.map(|x| i128::from(x.get())).map_err(range::Error::from)
+        };
+
+        prop_assert_eq!(
+            checked(a.checked_add(b)),
+            expected(a_wide + b_wide, -100..=100),
+        );
+        prop_assert_eq!(
+            checked(a.checked_sub(b)),
+            expected(a_wide - b_wide, -100..=100),
+        );
+        prop_assert_eq!(
+            checked(a.checked_mul(b)),
+            expected(a_wide * b_wide, -100..=100),
+        );
+    }
+
+    #[test]
+    fn checked_unsigned(a: Unsigned, b: u16) {
+        let (a_wide, b_wide) = (i128::from(a.get()), i128::from(b));
+        let checked = |result: Option<Unsigned>| {
+            result.map(|x| i128::from(x.get()))
+        };
+
+        prop_assert_eq!(
+            checked(a.checked_add(b)),
+            expected(a_wide + b_wide, 1000..=60000).ok(),
+        );
+        prop_assert_eq!(
+            checked(a.checked_sub(b)),
+            expected(a_wide - b_wide, 1000..=60000).ok(),
+        );
+
+        match a.checked_div(b) {
+            Some(Quotient::Nan) => prop_assert_eq!(b, 0),
+            Some(Quotient::Number(quotient)) => {
+                prop_assert_eq!(quotient.get(), a.get() / b);
+            }
+            None => prop_assert!(b != 0 && a.get() / b < 1000),
+        }
+    }
+
+    #[test]
+    fn checked_nonzero(a: NonZero, b: i16) {
+        let (a_wide, b_wide) = (i128::from(a.get()), i128::from(b));
+        let checked = |result: ranch::Result<Option<NonZero>>| {
+            range::result(result).map(|x| i128::from(x.get()))
+        };
+
+        prop_assert_ne!(a.get(), 0);
+        prop_assert_eq!(
+            checked(a.checked_add(b)),
+            expected_nonzero(a_wide + b_wide, -300..=300),
+        );
+        prop_assert_eq!(
+            checked(a.checked_sub(b)),
+            expected_nonzero(a_wide - b_wide, -300..=300),
+        );
+        prop_assert_eq!(
+            checked(a.checked_mul(b)),
+            expected_nonzero(a_wide * b_wide, -300..=300),
+        );
+    }
+
+    #[test]
+    fn checked_wide(a: Wide, b: u8) {
+        let b = u128::from(b);
+
+        prop_assert_eq!(a.checked_add(b).map(Wide::get), a.get().checked_add(b));
+        prop_assert_eq!(
+            a.checked_sub(b).map(Wide::get),
+            a.get().checked_sub(b).filter(|x| *x >= Wide::MIN.get()),
+        );
+    }
+}
+
+#[test]
+fn proptest_shrinks_to_min() {
+    let mut runner = TestRunner::new(Config::default());
+
+    for _ in 0..32 {
+        let mut tree = any::<NonZero>().new_tree(&mut runner).unwrap();
+
+        while tree.simplify() {}
+
+        assert_eq!(tree.current(), NonZero::MIN);
+
+        let mut tree = any::<RangedI32<-5, 5>>().new_tree(&mut runner).unwrap();
+
+        while tree.simplify() {}
+
+        assert_eq!(tree.current(), RangedI32::MIN);
+    }
+}
+
+#[test]
+fn arbitrary_in_range() {
+    let data: Vec<u8> = (0..=u8::MAX).cycle().step_by(7).take(4096).collect();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+        let signed = Signed::arbitrary(&mut u).unwrap();
+        let nonzero = NonZero::arbitrary(&mut u).unwrap();
+        let hour = RangedNonZeroU8::<1, 12>::arbitrary(&mut u).unwrap();
+
+        assert!((-100..=100).contains(&signed.get()));
+        assert!((-300..=300).contains(&nonzero.get()) && nonzero.get() != 0);
+        assert!((1..=12).contains(&hour.get()));
+    }
+
+    let mut empty = Unstructured::new(&[]);
+
+    assert_eq!(Signed::arbitrary(&mut empty), Ok(Signed::MIN));
+    assert_eq!(NonZero::arbitrary(&mut empty), Ok(NonZero::MIN));
+    assert_eq!(Unsigned::arbitrary(&mut empty), Ok(Unsigned::MIN));
+}
+
+macro_rules! checked_add_overflow {
+    ($name:ident, $type:ident, $p:ident) => {
+        #[test]
+        fn $name() {
+            type Narrow = $type<-10, 10>;
+
+            assert_eq!(
+                Narrow::new::<10>().checked_add($p::MAX),
+                Err(Error::PosOverflow),
+            );
+            assert_eq!(
+                Narrow::new::<-10>().checked_add($p::MIN),
+                Err(Error::NegOverflow),
+            );
+            assert_eq!(
+                Narrow::new::<10>().checked_add(1),
+                Err(Error::PosOverflow),
+            );
+            assert_eq!(
+                Narrow::new::<-10>().checked_add(-1),
+                Err(Error::NegOverflow),
+            );
+        }
+    };
+}
+
+checked_add_overflow!(checked_add_overflow_i8, RangedI8, i8);
+checked_add_overflow!(checked_add_overflow_i16, RangedI16, i16);
+checked_add_overflow!(checked_add_overflow_i32, RangedI32, i32);
+checked_add_overflow!(checked_add_overflow_i64, RangedI64, i64);
+checked_add_overflow!(checked_add_overflow_i128, RangedI128, i128);
+checked_add_overflow!(checked_add_overflow_isize, RangedIsize, isize);
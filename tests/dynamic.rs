@@ -0,0 +1,89 @@
+use ranch::{DynRangedI8, DynRangedU16, Error, RangedI8, RangedU16};
+
+type Signed = RangedI8<-10, 10>;
+type Unsigned = RangedU16<100, 300>;
+
+#[test]
+fn dynamic_signed() {
+    for a in Signed::all() {
+        let a_dyn = DynRangedI8::from(a);
+
+        assert_eq!(Signed::try_from(a_dyn), Ok(a));
+
+        for b in i8::MIN..=i8::MAX {
+            assert_eq!(a_dyn.checked_add(b), a.checked_add(b).map(From::from));
+            assert_eq!(a_dyn.checked_sub(b), a.checked_sub(b).map(From::from));
+            assert_eq!(a_dyn.checked_mul(b), a.checked_mul(b).map(From::from));
+            assert_eq!(
+                a_dyn.checked_div(b),
+                a.checked_div(b).map(|q| q.map(From::from)),
+            );
+            assert_eq!(a_dyn.saturating_add(b), a.saturating_add(b).into());
+            assert_eq!(a_dyn.saturating_sub(b), a.saturating_sub(b).into());
+            assert_eq!(a_dyn.saturating_mul(b), a.saturating_mul(b).into());
+            assert_eq!(
+                a_dyn.saturating_div(b),
+                a.saturating_div(b).map(From::from),
+            );
+        }
+
+        for exp in 0..=8 {
+            assert_eq!(
+                a_dyn.checked_pow(exp),
+                a.checked_pow(exp).map(From::from),
+            );
+            assert_eq!(a_dyn.saturating_pow(exp), a.saturating_pow(exp).into());
+        }
+    }
+}
+
+#[test]
+fn dynamic_unsigned() {
+    for c in Unsigned::all() {
+        let c_dyn = DynRangedU16::from(c);
+
+        assert_eq!(Unsigned::try_from(c_dyn), Ok(c));
+
+        for d in 0..=400 {
+            assert_eq!(c_dyn.checked_add(d), c.checked_add(d).map(From::from));
+            assert_eq!(c_dyn.checked_sub(d), c.checked_sub(d).map(From::from));
+            assert_eq!(c_dyn.checked_mul(d), c.checked_mul(d).map(From::from));
+            assert_eq!(
+                c_dyn.checked_div(d),
+                c.checked_div(d).map(|q| q.map(From::from)),
+            );
+            assert_eq!(c_dyn.saturating_add(d), c.saturating_add(d).into());
+            assert_eq!(c_dyn.saturating_sub(d), c.saturating_sub(d).into());
+            assert_eq!(c_dyn.saturating_mul(d), c.saturating_mul(d).into());
+        }
+
+        for exp in 0..=4 {
+            assert_eq!(
+                c_dyn.checked_pow(exp),
+                c.checked_pow(exp).map(From::from),
+            );
+            assert_eq!(c_dyn.saturating_pow(exp), c.saturating_pow(exp).into());
+        }
+    }
+}
+
+#[test]
+fn dynamic_to_ranged() {
+    for value in -10..=10 {
+        let a = DynRangedI8::with_bounds(-10, 10, value).unwrap();
+
+        assert_eq!(a.to_ranged::<-10, 10>().map(RangedI8::get), Ok(value));
+        assert_eq!(a.to_ranged::<-20, 20>().map(RangedI8::get), Ok(value));
+        assert_eq!(a.to_ranged::<-9, 10>(), Err(Error::NegOverflow));
+        assert_eq!(a.to_ranged::<-10, 9>(), Err(Error::PosOverflow));
+        assert_eq!(RangedI8::<-5, 5>::try_from(a), Err(Error::NegOverflow));
+    }
+
+    for value in 100..=300 {
+        let c = DynRangedU16::with_bounds(100, 300, value).unwrap();
+
+        assert_eq!(c.to_ranged::<0, 300>().map(RangedU16::get), Ok(value));
+        assert_eq!(c.to_ranged::<101, 300>(), Err(Error::NegOverflow));
+        assert_eq!(c.to_ranged::<100, 299>(), Err(Error::PosOverflow));
+    }
+}
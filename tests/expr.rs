@@ -0,0 +1,47 @@
+use ranch::{RangedI16, RangedI64, RangedU32, expr};
+
+type Small = RangedI16<-3, 4>;
+type Wide = RangedI64<-100, 100>;
+
+#[test]
+fn expr_precedence() {
+    let a = Small::new::<-2>();
+    let b = Small::new::<4>();
+
+    let sum: RangedI16<-14, 0> =
+        expr!(RangedI16; a: Small, b: Small => a - b - 7);
+    let product: RangedI16<-14, 21> =
+        expr!(RangedI16; a: Small, b: Small => a + b * a + 1);
+    let grouped: RangedI16<-63, 84> =
+        expr!(RangedI16; a: Small, b: Small => -3 * (b - 1) * (a + 3));
+
+    assert_eq!(sum.get(), -13);
+    assert_eq!(product.get(), -9);
+    assert_eq!(grouped.get(), -9);
+}
+
+#[test]
+fn expr_negative_literal() {
+    let a = Wide::new::<7>();
+    let output: RangedI64<-105, 95> = expr!(RangedI64; a: Wide => -5 + a);
+    let negated: RangedI64<-100, 100> = expr!(RangedI64; a: Wide => -1 * a);
+
+    assert_eq!(output.get(), 2);
+    assert_eq!(negated.get(), -7);
+}
+
+#[test]
+fn expr_type() {
+    type Hours = RangedU32<0, 23>;
+    type Minutes = RangedU32<0, 59>;
+    type Total = expr!(type RangedU32 => Hours * 60 + Minutes);
+
+    let hours = Hours::new::<12>();
+    let minutes = Minutes::new::<30>();
+    let total: Total = expr!(
+        RangedU32; hours: Hours, minutes: Minutes => hours * 60 + minutes
+    );
+
+    assert_eq!(Total::MAX, RangedU32::<0, 1439>::MAX);
+    assert_eq!(total.get(), 750);
+}
@@ -0,0 +1,17 @@
+use ranch::{
+    bitwise::{U4, U8},
+    index::RangedIndex,
+};
+
+#[test]
+fn index() {
+    let mut table = [0u32; 16];
+
+    for nibble in U4::all() {
+        *table.get_ranged_mut(nibble) = nibble.get().count_ones();
+    }
+
+    assert_eq!(*table.get_ranged(U4::new::<0b1011>()), 3);
+    assert_eq!(*table.get_ranged(U4::MAX.to_ranged_u128::<0, 15>()), 4);
+    assert_eq!(*[7; 256].get_ranged(U8::MAX), 7);
+}
@@ -0,0 +1,40 @@
+use ranch::{
+    RangedI32, RangedNonZeroI16, RangedU128,
+    bitwise::{I4, I8, NonZeroI4, NonZeroI8, NonZeroU8, U4, U8},
+    range::Iter,
+};
+
+#[test]
+fn iter() {
+    assert!(U8::all().map(U8::get).eq(0..=255));
+    assert!(I8::all().rev().map(I8::get).eq((-128..=127).rev()));
+    assert!(NonZeroU8::all().map(NonZeroU8::get).eq(1..=255));
+    assert_eq!(NonZeroI8::all().len(), 255);
+    assert_eq!(NonZeroI8::all().filter(|x| x.get() == 0).count(), 0);
+    assert_eq!(NonZeroI4::all().rev().nth(7), Some(NonZeroI4::new::<-1>()));
+
+    let mut iter = Iter::from(I4::new::<-1>()..=I4::new::<1>());
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(I4::new::<1>()));
+    assert_eq!(iter.next(), Some(I4::new::<-1>()));
+    assert_eq!(iter.next(), Some(I4::new::<0>()));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let empty = Iter::new(U4::new::<3>(), U4::new::<2>());
+
+    assert_eq!(empty.len(), 0);
+    assert_eq!(RangedNonZeroI16::<-300, 300>::all().len(), 600);
+    assert_eq!(RangedU128::<0, 9>::all().size_hint(), (10, Some(10)));
+    assert_eq!(RangedU128::<0, { u128::MAX }>::all().size_hint().1, None);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn iter_len_32_bit() {
+    assert_eq!(
+        RangedI32::<{ i32::MIN }, { i32::MAX }>::all().len(),
+        1 << 32
+    );
+}
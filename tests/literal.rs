@@ -0,0 +1,18 @@
+use ranch::{
+    RangedI16, RangedNonZeroI128,
+    bitwise::{I4, U12, U128},
+    range_type, ranged,
+};
+
+#[test]
+fn range_type() {
+    type Big = range_type!(0..=340282366920938463463374607431768211455);
+    type Small = range_type!(-170141183460469231731687303715884105728..=-1);
+    type Signed = range_type!(-128..=128);
+
+    assert_eq!(Big::MAX, U128::MAX);
+    assert_eq!(Small::MIN, RangedNonZeroI128::<{ i128::MIN }, -1>::MIN);
+    assert_eq!(Signed::MAX, RangedI16::<-128, 128>::MAX);
+    assert_eq!(ranged!(-8, -8..=7), I4::MIN);
+    assert_eq!(ranged!(4095: U12), U12::MAX);
+}
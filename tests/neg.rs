@@ -0,0 +1,24 @@
+use ranch::{
+    Error, RangedI8, RangedU8,
+    bitwise::{I4, NonZeroI4},
+};
+
+#[test]
+fn neg() {
+    assert_eq!(-I4::new::<3>(), I4::new::<-3>());
+    assert_eq!(I4::MIN.checked_neg(), Err(Error::PosOverflow));
+    assert_eq!(I4::MIN.saturating_neg(), I4::MAX);
+
+    let output: RangedU8<0, 8> = I4::MIN.unsigned_abs();
+    assert_eq!(output.get(), 8);
+
+    let output: RangedI8<-7, 8> = I4::new::<-7>().neg_ranged();
+    assert_eq!(output.get(), 7);
+    assert_eq!(-NonZeroI4::new::<-2>(), NonZeroI4::new::<2>());
+
+    let output: RangedI8<-127, 127> = RangedI8::<-127, 127>::MIN.neg_ranged();
+    assert_eq!(output, RangedI8::MAX);
+
+    let output: RangedI8<1, 127> = RangedI8::<-127, -1>::MIN.abs_ranged();
+    assert_eq!(output, RangedI8::MAX);
+}
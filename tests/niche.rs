@@ -0,0 +1,27 @@
+use core::mem::size_of;
+
+use ranch::{
+    OptionRanged, RangedI16,
+    bitwise::{I4, I12, NonZeroU8, U7},
+};
+
+#[test]
+fn option() {
+    assert_eq!(size_of::<OptionRanged<U7>>(), 1);
+    assert_eq!(size_of::<OptionRanged<I12>>(), 2);
+    assert_eq!(size_of::<OptionRanged<RangedI16<{ i16::MIN }, 0>>>(), 2);
+    assert_eq!(size_of::<OptionRanged<NonZeroU8>>(), 1);
+
+    let mut table = [OptionRanged::<I4>::NONE; 4];
+
+    table[1] = I4::MIN.into();
+    table[2] = I4::MAX.into();
+    assert!(table[0] < table[1] && table[1] < table[2]);
+    assert_eq!(table[3].replace(I4::new::<-1>()), None);
+    assert_eq!(table[3].filter(|value| value.get() > 0), OptionRanged::NONE);
+    assert_eq!(format!("{:?}", table[2]), "Some(7)");
+    assert_eq!(
+        OptionRanged::<NonZeroU8>::default().ok_or(()),
+        Err::<NonZeroU8, _>(())
+    );
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, FromPrimitive, NumCast, Saturating,
+    SaturatingMul, ToPrimitive,
+};
+use ranch::{
+    RangedNonZeroI16,
+    bitwise::{I4, U4, U128},
+};
+
+fn sum<T: Bounded + CheckedAdd>(values: &[T]) -> Option<T> {
+    values
+        .iter()
+        .try_fold(T::min_value(), |sum, value| sum.checked_add(value))
+}
+
+#[test]
+fn num_traits() {
+    assert_eq!(sum(&[U4::new::<3>(), U4::new::<4>()]), Some(U4::new::<7>()));
+    assert_eq!(sum(&[U4::new::<9>(), U4::new::<7>()]), None);
+    assert_eq!(
+        SaturatingMul::saturating_mul(&I4::new::<-4>(), &I4::new::<3>()),
+        I4::MIN,
+    );
+    assert_eq!(CheckedDiv::checked_div(&U4::MAX, &U4::MIN), None);
+    assert_eq!(
+        CheckedDiv::checked_div(&U4::MAX, &U4::new::<2>()),
+        Some(U4::new::<7>()),
+    );
+
+    type Offset = RangedNonZeroI16<-300, 300>;
+
+    assert_eq!(Offset::max_value(), Offset::MAX);
+    assert_eq!(Offset::from_i64(-300), Some(Offset::MIN));
+    assert_eq!(Offset::from_i64(0), None);
+    assert_eq!(Offset::from_u128(301), None);
+    assert_eq!(Offset::from_f64(-2.5), Some(Offset::new::<-2>()));
+    assert_eq!(<Offset as NumCast>::from(1_u64 << 40), None);
+    assert_eq!(Offset::MIN.to_u8(), None);
+    assert_eq!(Offset::MIN.to_f32(), Some(-300.0));
+    assert_eq!(Offset::MAX.saturating_sub(Offset::MIN), Offset::MAX);
+    assert_eq!(U128::MAX.to_u128(), Some(u128::MAX));
+    assert_eq!(
+        <U128 as FromPrimitive>::from_u128(u128::MAX),
+        Some(U128::MAX)
+    );
+}
@@ -0,0 +1,55 @@
+use ranch::{
+    RangedI8, RangedU8,
+    bitwise::{I1, I5, I128, U1, U7, U12, U128},
+    pack::{BitOrder, BitReader, BitWriter, Error, Pack},
+};
+
+#[test]
+fn pack() {
+    assert_eq!(I1::WIDTH, 1);
+    assert_eq!(I128::WIDTH, 128);
+    assert_eq!(U128::WIDTH, 128);
+    assert_eq!(RangedI8::<-8, -1>::WIDTH, 4);
+    assert_eq!(RangedI8::<0, 0>::WIDTH, 0);
+
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut buffer = [0xA5; 80];
+        let mut writer = BitWriter::new(&mut buffer, order);
+
+        for (signed, unsigned) in I5::all().zip(U7::all()) {
+            writer.write(signed).unwrap();
+            writer.write(unsigned).unwrap();
+        }
+
+        writer.write(I128::MIN).unwrap();
+        writer.write(U128::MAX).unwrap();
+        assert_eq!(writer.position(), 32 * 12 + 256);
+
+        let mut reader = BitReader::new(&buffer, order);
+
+        for (signed, unsigned) in I5::all().zip(U7::all()) {
+            assert_eq!(reader.read(), Ok(signed));
+            assert_eq!(reader.read(), Ok(unsigned));
+        }
+
+        assert_eq!(reader.read(), Ok(I128::MIN));
+        assert_eq!(reader.read(), Ok(U128::MAX));
+        assert_eq!(reader.remaining(), 0);
+        assert_eq!(reader.read::<U1>(), Err(Error::EndOfBuffer));
+    }
+
+    let mut buffer = [0; 2];
+    let mut writer = BitWriter::new(&mut buffer, BitOrder::LsbFirst);
+
+    writer.write(U12::new::<0xABC>()).unwrap();
+    assert_eq!(writer.finish(), [0xBC, 0x0A]);
+
+    let mut reader = BitReader::new(&[0xFF], BitOrder::MsbFirst);
+
+    assert_eq!(reader.read::<RangedU8<0, 100>>(), Err(Error::PosOverflow));
+    assert_eq!(reader.position(), 0);
+
+    let mut reader = BitReader::new(&[0x80], BitOrder::MsbFirst);
+
+    assert_eq!(reader.read::<RangedI8<-5, 5>>(), Err(Error::NegOverflow));
+}
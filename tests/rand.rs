@@ -0,0 +1,33 @@
+#![cfg(feature = "rand")]
+
+use ranch::{RangedNonZeroI8, bitwise::U4};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+#[test]
+fn rand() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let mut counts = [0u32; 16];
+
+    for _ in 0..16_000 {
+        let nibble: U4 = rng.random();
+
+        counts[usize::from(nibble.get())] += 1;
+    }
+
+    assert!(counts.iter().all(|&count| (800..1200).contains(&count)));
+
+    let low = RangedNonZeroI8::<-3, 3>::new::<-2>();
+    let high = RangedNonZeroI8::<-3, 3>::new::<2>();
+    let mut seen = [false; 5];
+
+    for _ in 0..1_000 {
+        let value = rng.random_range(low..=high).get();
+
+        assert_ne!(value, 0);
+        seen[usize::try_from(value + 2).unwrap()] = true;
+        assert_ne!(rng.random_range(low..high).get(), 2);
+    }
+
+    assert_eq!(seen, [true, true, false, true, true]);
+    assert_eq!(rng.random_range(U4::MAX..=U4::MAX), U4::MAX);
+}
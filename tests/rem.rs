@@ -0,0 +1,29 @@
+use ranch::bitwise::{I8, NonZeroI8, NonZeroU4, NonZeroU8, U4, U8};
+
+#[test]
+fn rem() {
+    let a = U8::new::<200>();
+    let output: ranch::RangedU8<0, 15> = a.rem::<16, _, _>();
+
+    assert_eq!(output.get(), 8);
+    assert_eq!(a % NonZeroU8::new::<7>(), U8::new::<4>());
+
+    let b = I8::new::<-100>();
+    let output: ranch::RangedI8<-7, 7> = b.rem::<-8, _, _>();
+    let euclid: ranch::RangedI8<0, 7> = b.rem_euclid::<-8, _, _>();
+
+    assert_eq!(output.get(), -4);
+    assert_eq!(euclid.get(), 4);
+
+    let output: ranch::RangedI8<-127, 127> = I8::MIN.rem_ranged(NonZeroI8::MIN);
+    assert_eq!(output.get(), 0);
+    assert_eq!(
+        I8::MIN.checked_rem(NonZeroI8::new::<-1>()),
+        Ok(I8::new::<0>())
+    );
+
+    let mut c = U4::new::<15>();
+
+    c %= NonZeroU4::new::<4>();
+    assert_eq!(c.get(), 3);
+}
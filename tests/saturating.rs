@@ -0,0 +1,59 @@
+use core::num::NonZero;
+
+use ranch::{RangedI8, RangedU8, Saturating};
+
+#[test]
+fn saturating() {
+    type Gain = Saturating<RangedI8<-100, 100>>;
+    type Level = Saturating<RangedU8<10, 50>>;
+
+    let gain = Gain::MAX;
+
+    assert_eq!(gain + 1, Gain::MAX);
+    assert_eq!(gain * 2, Gain::MAX);
+    assert_eq!(gain * -2, Gain::MIN);
+    assert_eq!(-gain - 1, Gain::MIN);
+    assert_eq!(Gain::MIN - 1, Gain::MIN);
+    assert_eq!(-Gain::MIN, Gain::MAX);
+    assert_eq!(gain / -1, Gain::MIN);
+    assert_eq!(gain / 3, Saturating(RangedI8::new::<33>()));
+    assert_eq!(
+        gain % NonZero::new(30i8).unwrap(),
+        Saturating(RangedI8::new::<10>())
+    );
+    assert_eq!(
+        Gain::MIN % NonZero::new(-30i8).unwrap(),
+        Saturating(RangedI8::new::<-10>())
+    );
+
+    let level = Level::MAX;
+
+    assert_eq!(level + 1, Level::MAX);
+    assert_eq!(level / 10, Level::MIN);
+    assert_eq!(level / 2, Saturating(RangedU8::new::<25>()));
+    assert_eq!(level % NonZero::new(7u8).unwrap(), Level::MIN);
+    assert_eq!(
+        level % NonZero::new(30u8).unwrap(),
+        Saturating(RangedU8::new::<20>())
+    );
+    assert_eq!(Level::MIN - 1, Level::MIN);
+
+    let mut level = Level::MIN;
+
+    level += 35;
+    assert_eq!(level, Saturating(RangedU8::new::<45>()));
+    level *= 2;
+    assert_eq!(level, Level::MAX);
+    level /= 3;
+    assert_eq!(level, Saturating(RangedU8::new::<16>()));
+    level %= NonZero::new(9u8).unwrap();
+    assert_eq!(level, Level::MIN);
+    level -= 1;
+    assert_eq!(level, Level::MIN);
+}
+
+#[test]
+#[should_panic = "cannot divide by zero"]
+fn saturating_div_zero() {
+    let _ = Saturating(RangedU8::<0, 10>::new::<5>()) / 0;
+}
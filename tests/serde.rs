@@ -0,0 +1,67 @@
+#![cfg(feature = "serde")]
+
+use ranch::{RangedI128, RangedNonZeroI8, bitwise::U4};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn serde() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Packet {
+        kind: U4,
+        offset: RangedNonZeroI8<-4, 4>,
+        #[serde(with = "ranch::serde::as_string")]
+        checksum: RangedI128<{ i128::MIN }, 0>,
+    }
+
+    let packet = Packet {
+        kind: U4::new::<9>(),
+        offset: RangedNonZeroI8::new::<-4>(),
+        checksum: RangedI128::MIN,
+    };
+    let json = serde_json::to_string(&packet).unwrap();
+    let error = |json: &str| {
+        serde_json::from_str::<Packet>(json)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"kind":9,"offset":-4,"#,
+            r#""checksum":"-170141183460469231731687303715884105728"}"#,
+        ),
+    );
+    assert_eq!(serde_json::from_str::<Packet>(&json).unwrap(), packet);
+    assert!(
+        error(r#"{"kind":16,"offset":1,"checksum":"0"}"#)
+            .starts_with("expected integer in 0..=15, got 16")
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":0,"checksum":"0"}"#)
+            .starts_with("expected non-zero integer in -4..=4, got 0")
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":1,"checksum":"1"}"#).starts_with(
+            "expected integer in -170141183460469231731687303715884105728..=0, \
+             got 1"
+        )
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":1,"checksum":"one"}"#)
+            .starts_with("invalid value: string \"one\", expected integer in")
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":1,"checksum":0}"#)
+            .starts_with("invalid type: integer `0`, expected integer in")
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":5,"checksum":"0"}"#)
+            .starts_with("expected non-zero integer in -4..=4, got 5")
+    );
+    assert!(
+        error(r#"{"kind":1,"offset":"1","checksum":"0"}"#).starts_with(
+            "invalid type: string \"1\", expected non-zero integer in -4..=4"
+        )
+    );
+}
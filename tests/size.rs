@@ -0,0 +1,134 @@
+use ranch::{
+    Error, RangedI64, RangedI128, RangedIsize, RangedNonZeroIsize,
+    RangedNonZeroUsize, RangedU64, RangedU128, RangedUsize,
+    bitwise::{I4, U4},
+    parsing,
+};
+
+type Index = RangedUsize<0, { usize::MAX }>;
+type Offset = RangedIsize<{ isize::MIN }, { isize::MAX }>;
+
+#[test]
+fn size() {
+    let mut array = [0u8; 16];
+    let index = U4::MAX.to_ranged_usize::<0, 15>();
+
+    array[index] = 1;
+    assert_eq!(array[RangedUsize::<15, 15>::new::<15>()], 1);
+    assert_eq!(index.checked_add(1), None);
+    assert_eq!(index.saturating_sub(20), RangedUsize::MIN);
+    assert_eq!(RangedUsize::<0, 15>::all().count(), 16);
+    assert_eq!(
+        RangedIsize::<-8, 7>::with_isize(-9),
+        Err(Error::NegOverflow)
+    );
+    assert_eq!(
+        RangedIsize::<-8, 7>::new::<-8>().to_ranged_i8::<-8, 7>(),
+        I4::MIN
+    );
+    assert_eq!(
+        index.to_ranged_nonzero(),
+        Some(RangedNonZeroUsize::<1, 15>::new::<15>())
+    );
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn size_conversion_limits() {
+    let max = RangedU64::<0, { u64::MAX }>::MAX;
+    let min = RangedI64::<{ i64::MIN }, { i64::MAX }>::MIN;
+    let index: Index = max.to_ranged_usize();
+    let offset: Offset = min.to_ranged_isize();
+
+    assert_eq!(index, Index::MAX);
+    assert_eq!(offset, Offset::MIN);
+    assert_eq!(index.to_ranged_u64(), max);
+    assert_eq!(offset.to_ranged_i64(), min);
+    assert_eq!(
+        index.to_ranged_u128::<0, { u128::MAX }>().get(),
+        u128::from(u64::MAX),
+    );
+    assert_eq!(
+        index.to_ranged_i128::<0, { i128::MAX }>().get(),
+        i128::from(u64::MAX),
+    );
+    assert_eq!(
+        offset
+            .to_ranged_i128::<{ i128::MIN }, { i128::MAX }>()
+            .get(),
+        i128::from(i64::MIN),
+    );
+    assert_eq!(
+        RangedU128::<0, { u64::MAX as u128 }>::MAX.to_ranged_usize(),
+        index,
+    );
+    assert_eq!(
+        RangedI128::<{ i64::MIN as i128 }, { i64::MAX as i128 }>::MIN
+            .to_ranged_isize(),
+        offset,
+    );
+
+    // across signedness, at the top of the signed range
+    assert_eq!(
+        RangedU64::<0, { i64::MAX as u64 }>::MAX
+            .to_ranged_isize::<0, { isize::MAX }>()
+            .get(),
+        isize::MAX,
+    );
+    assert_eq!(
+        RangedI64::<0, { i64::MAX }>::MAX
+            .to_ranged_usize::<0, { usize::MAX }>()
+            .get(),
+        i64::MAX as usize,
+    );
+    assert_eq!(
+        RangedUsize::<0, { isize::MAX as usize }>::MAX
+            .to_ranged_i64::<0, { i64::MAX }>()
+            .get(),
+        i64::MAX,
+    );
+    assert_eq!(
+        RangedIsize::<0, { isize::MAX }>::MAX
+            .to_ranged_u64::<0, { u64::MAX }>()
+            .get(),
+        i64::MAX as u64,
+    );
+}
+
+#[test]
+fn size_parse() {
+    assert_eq!(usize::MAX.to_string().parse(), Ok(Index::MAX));
+    assert_eq!(isize::MIN.to_string().parse(), Ok(Offset::MIN));
+    assert_eq!("15".parse(), Ok(RangedUsize::<0, 15>::new::<15>()));
+    assert_eq!(
+        "16".parse::<RangedUsize<0, 15>>(),
+        Err(parsing::Error::PosOverflow)
+    );
+    assert_eq!(
+        "-9".parse::<RangedIsize<-8, 7>>(),
+        Err(parsing::Error::NegOverflow)
+    );
+    assert!(matches!(
+        "-1".parse::<RangedUsize<0, 15>>(),
+        Err(parsing::Error::ParseInt(_))
+    ));
+    assert_eq!("-8".parse(), Ok(RangedNonZeroIsize::<-8, 8>::new::<-8>()));
+    assert_eq!(
+        "9".parse::<RangedNonZeroIsize<-8, 8>>(),
+        Err(parsing::Error::PosOverflow)
+    );
+    assert!(matches!(
+        "0".parse::<RangedNonZeroUsize<1, 8>>(),
+        Err(parsing::Error::ParseInt(_))
+    ));
+}
+
+#[test]
+fn size_display() {
+    assert_eq!(Index::MAX.to_string(), usize::MAX.to_string());
+    assert_eq!(Offset::MIN.to_string(), isize::MIN.to_string());
+    assert_eq!(format!("{:x}", Index::MAX), format!("{:x}", usize::MAX));
+    assert_eq!(format!("{:>4}", RangedUsize::<0, 15>::new::<15>()), "  15");
+    assert_eq!(RangedNonZeroIsize::<-8, 8>::new::<-8>().to_string(), "-8");
+    assert_eq!(format!("{:?}", RangedNonZeroUsize::<1, 8>::new::<8>()), "8");
+}
@@ -0,0 +1,49 @@
+use ranch::{
+    Error, RangedI8, RangedI128, RangedU8,
+    bitwise::{I4, U4, U8},
+};
+
+#[test]
+fn sum() {
+    let total: u8 = U4::all().sum();
+    let product: i8 = [I4::new::<-2>(), I4::new::<3>()].iter().product();
+
+    assert_eq!(total, 120);
+    assert_eq!(product, -6);
+    assert_eq!(I4::checked_sum([7, 7, -7]), Ok(I4::new::<7>()));
+    assert_eq!(I4::checked_sum([-8, -1]), Err(Error::NegOverflow));
+    assert_eq!(I4::saturating_sum([-8, -1]), I4::MIN);
+    assert_eq!(U4::checked_sum(U4::all()), None);
+    assert_eq!(I4::checked_product([-8, 2, 4]), Err(Error::NegOverflow));
+}
+
+#[test]
+fn sum_partial_overflow() {
+    type Full = RangedI8<{ i8::MIN }, { i8::MAX }>;
+    type Byte = RangedU8<0, { u8::MAX }>;
+
+    assert_eq!(Full::saturating_sum([127, 1, -100]).get(), 28);
+    assert_eq!(Full::checked_sum([-128, -1, 1]), Ok(Full::MIN));
+    assert_eq!(Full::checked_sum([127, 1]), Err(Error::PosOverflow));
+    assert_eq!(Byte::saturating_product([16, 16, 0]).get(), 0);
+    assert_eq!(Byte::checked_product([16, 16, 0]), Some(Byte::MIN));
+    assert_eq!(Full::checked_product([-128, -128, 0]), Ok(Full::new::<0>()));
+    assert_eq!(
+        Full::checked_product([-128, 2, -1]),
+        Err(Error::PosOverflow)
+    );
+    assert_eq!(
+        RangedI128::<-1, 1>::checked_sum([i128::MAX, 1, i128::MIN]),
+        Ok(RangedI128::new::<0>()),
+    );
+    assert_eq!(
+        RangedI128::<-1, 1>::checked_product([i128::MAX, i128::MAX, -1]),
+        Err(Error::NegOverflow),
+    );
+}
+
+#[test]
+#[should_panic = "out of range"]
+fn sum_overflow() {
+    let _: u8 = U8::all().sum();
+}
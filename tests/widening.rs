@@ -0,0 +1,104 @@
+use ranch::{
+    RangedI8, RangedI16, RangedI64, RangedI128, RangedU8, RangedU16, RangedU64,
+    RangedU128,
+};
+
+type Byte = RangedU8<0, 255>;
+type Signed = RangedI8<-128, 127>;
+
+#[test]
+fn widening_unsigned() {
+    for a in 0..=u8::MAX {
+        for b in 0..=u8::MAX {
+            let (x, y) = (Byte::with_u8(a).unwrap(), Byte::with_u8(b).unwrap());
+            let sum: RangedU16<0, 510> = x.widening_add_ranged(y);
+            let product: RangedU16<0, 65025> = x.widening_mul_ranged(y);
+            let wide = RangedU16::<0, 1000>::with_u16(u16::from(b)).unwrap();
+            let wide_sum: RangedU16<0, 1255> = x.add_wide_ranged(wide);
+            let factor = RangedU16::<0, 256>::with_u16(u16::from(b)).unwrap();
+            let wide_product: RangedU16<0, 65280> = x.mul_wide_ranged(factor);
+
+            assert_eq!(sum.get(), u16::from(a) + u16::from(b));
+            assert_eq!(product.get(), u16::from(a) * u16::from(b));
+            assert_eq!(wide_sum.get(), u16::from(a) + u16::from(b));
+            assert_eq!(wide_product.get(), u16::from(a) * u16::from(b));
+
+            // unsigned differences only fit when the subtrahend is smaller
+            if let (Ok(high), Ok(low)) = (
+                RangedU8::<128, 255>::with_u8(a),
+                RangedU8::<0, 128>::with_u8(b),
+            ) {
+                let difference: RangedU16<0, 255> =
+                    high.widening_sub_ranged(low);
+                let low = low.to_ranged_u16::<0, 128>();
+                let wide_difference: RangedU16<0, 255> =
+                    high.sub_wide_ranged(low);
+
+                assert_eq!(difference.get(), u16::from(a) - u16::from(b));
+                assert_eq!(wide_difference.get(), u16::from(a) - u16::from(b));
+            }
+        }
+    }
+}
+
+#[test]
+fn widening_signed() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            let (x, y) =
+                (Signed::with_i8(a).unwrap(), Signed::with_i8(b).unwrap());
+            let sum: RangedI16<-256, 254> = x.widening_add_ranged(y);
+            let difference: RangedI16<-255, 255> = x.widening_sub_ranged(y);
+            let product: RangedI16<-16256, 16384> = x.widening_mul_ranged(y);
+            let wide = RangedI16::<-200, 200>::with_i16(i16::from(b)).unwrap();
+            let wide_sum: RangedI16<-328, 327> = x.add_wide_ranged(wide);
+            let wide_difference: RangedI16<-328, 327> = x.sub_wide_ranged(wide);
+            let wide_product: RangedI16<-25600, 25600> =
+                x.mul_wide_ranged(wide);
+
+            assert_eq!(sum.get(), i16::from(a) + i16::from(b));
+            assert_eq!(difference.get(), i16::from(a) - i16::from(b));
+            assert_eq!(product.get(), i16::from(a) * i16::from(b));
+            assert_eq!(wide_sum.get(), i16::from(a) + i16::from(b));
+            assert_eq!(wide_difference.get(), i16::from(a) - i16::from(b));
+            assert_eq!(wide_product.get(), i16::from(a) * i16::from(b));
+        }
+    }
+}
+
+#[test]
+fn widening_64_bit() {
+    type Unsigned = RangedU64<0, { u64::MAX }>;
+    type Signed = RangedI64<{ i64::MIN }, { i64::MAX }>;
+
+    let unsigned = [0, 1, 2, u64::MAX - 1, u64::MAX];
+    let signed = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+
+    for a in unsigned {
+        for b in unsigned {
+            let (x, y) = (
+                Unsigned::with_u64(a).unwrap(),
+                Unsigned::with_u64(b).unwrap(),
+            );
+            let product: RangedU128<
+                0,
+                { u64::MAX as u128 * u64::MAX as u128 },
+            > = x.widening_mul_ranged(y);
+
+            assert_eq!(product.get(), u128::from(a) * u128::from(b));
+        }
+    }
+
+    for a in signed {
+        for b in signed {
+            let (x, y) =
+                (Signed::with_i64(a).unwrap(), Signed::with_i64(b).unwrap());
+            let product: RangedI128<
+                { i64::MIN as i128 * i64::MAX as i128 },
+                { i64::MIN as i128 * i64::MIN as i128 },
+            > = x.widening_mul_ranged(y);
+
+            assert_eq!(product.get(), i128::from(a) * i128::from(b));
+        }
+    }
+}
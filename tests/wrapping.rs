@@ -0,0 +1,203 @@
+use core::num::NonZero;
+
+fn wrap_model(x: i32, min: i32, max: i32) -> (i32, i32) {
+    let width = max - min + 1;
+
+    (
+        min + (x - min).rem_euclid(width),
+        (x - min).div_euclid(width),
+    )
+}
+
+fn check_wrapping_signed<const MIN: i8, const MAX: i8>() {
+    let (min, max) = (i32::from(MIN), i32::from(MAX));
+
+    for value in MIN..=MAX {
+        let a = ranch::RangedI8::<MIN, MAX>::with_i8(value).unwrap();
+        let value = i32::from(value);
+        let (neg, count) = wrap_model(-value, min, max);
+
+        assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), count as i8));
+        assert_eq!(i32::from(a.wrapping_neg().get()), neg);
+
+        for other in i8::MIN..=i8::MAX {
+            let (sum, count) = wrap_model(value + i32::from(other), min, max);
+
+            assert_eq!(i32::from(a.wrapping_add(other).get()), sum);
+            assert_eq!(a.overflowing_add(other).1, count as i8);
+
+            let (diff, count) = wrap_model(value - i32::from(other), min, max);
+
+            assert_eq!(i32::from(a.wrapping_sub(other).get()), diff);
+            assert_eq!(a.overflowing_sub(other).1, count as i8);
+
+            let product = value * i32::from(other);
+            let (product_wrapped, count) = wrap_model(product, min, max);
+
+            assert_eq!(
+                a.overflowing_mul(other),
+                (
+                    ranch::RangedI8::with_i8(product_wrapped as i8).unwrap(),
+                    count as i8,
+                ),
+            );
+
+            if other == 0 {
+                assert!(a.wrapping_div(other).is_nan());
+                continue;
+            }
+
+            let (quotient, _) = wrap_model(value / i32::from(other), min, max);
+            let (remainder, _) = wrap_model(value % i32::from(other), min, max);
+
+            assert_eq!(
+                i32::from(a.wrapping_div(other).number().unwrap().get()),
+                quotient,
+            );
+            assert_eq!(
+                i32::from(a.wrapping_rem(NonZero::new(other).unwrap()).get()),
+                remainder,
+            );
+        }
+    }
+}
+
+fn check_wrapping_unsigned<const MIN: u8, const MAX: u8>() {
+    let (min, max) = (i32::from(MIN), i32::from(MAX));
+
+    for value in MIN..=MAX {
+        let a = ranch::RangedU8::<MIN, MAX>::with_u8(value).unwrap();
+        let value = i32::from(value);
+        let (neg, count) = wrap_model(-value, min, max);
+
+        assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), -count as u8));
+        assert_eq!(i32::from(a.wrapping_neg().get()), neg);
+
+        for other in u8::MIN..=u8::MAX {
+            let (sum, count) = wrap_model(value + i32::from(other), min, max);
+
+            assert_eq!(i32::from(a.wrapping_add(other).get()), sum);
+            assert_eq!(a.overflowing_add(other).1, count as u8);
+
+            let (diff, count) = wrap_model(value - i32::from(other), min, max);
+
+            assert_eq!(i32::from(a.wrapping_sub(other).get()), diff);
+            assert_eq!(a.overflowing_sub(other).1, -count as u8);
+
+            let product = value * i32::from(other);
+            let (product_wrapped, count) = wrap_model(product, min, max);
+
+            assert_eq!(
+                a.overflowing_mul(other),
+                (
+                    ranch::RangedU8::with_u8(product_wrapped as u8).unwrap(),
+                    count as u8,
+                ),
+            );
+
+            if other == 0 {
+                assert!(a.wrapping_div(other).is_nan());
+                continue;
+            }
+
+            let (quotient, _) = wrap_model(value / i32::from(other), min, max);
+            let (remainder, _) = wrap_model(value % i32::from(other), min, max);
+
+            assert_eq!(
+                i32::from(a.wrapping_div(other).number().unwrap().get()),
+                quotient,
+            );
+            assert_eq!(
+                i32::from(a.wrapping_rem(NonZero::new(other).unwrap()).get()),
+                remainder,
+            );
+        }
+    }
+}
+
+#[test]
+fn wrapping_ops() {
+    check_wrapping_signed::<-128, 127>();
+    check_wrapping_signed::<-128, 126>();
+    check_wrapping_signed::<-5, 6>();
+    check_wrapping_signed::<-9, -2>();
+    check_wrapping_signed::<3, 100>();
+    check_wrapping_signed::<4, 4>();
+
+    check_wrapping_unsigned::<0, 255>();
+    check_wrapping_unsigned::<1, 255>();
+    check_wrapping_unsigned::<1, 12>();
+    check_wrapping_unsigned::<0, 59>();
+    check_wrapping_unsigned::<200, 250>();
+    check_wrapping_unsigned::<7, 7>();
+}
+
+#[test]
+fn wrapping_mul_wide() {
+    type Full = ranch::RangedI128<{ i128::MIN }, { i128::MAX }>;
+    type Hours = ranch::RangedU128<1, 12>;
+
+    assert_eq!(Full::MAX.overflowing_mul(2), (Full::new::<-2>(), 1));
+    assert_eq!(Full::MIN.overflowing_mul(-1), (Full::MIN, 1));
+    assert_eq!(
+        Full::MIN.overflowing_mul(i128::MIN),
+        (Full::new::<0>(), 1 << 126),
+    );
+    assert_eq!(
+        Hours::MAX.overflowing_mul(u128::MAX),
+        (Hours::MAX, u128::MAX - 1),
+    );
+    assert_eq!(Hours::MIN.overflowing_mul(0), (Hours::MAX, u128::MAX));
+}
+
+#[test]
+fn wrapping_type() {
+    type Hour = ranch::Wrapping<ranch::RangedU8<1, 12>>;
+    type Offset = ranch::Wrapping<ranch::RangedI8<-4, 4>>;
+
+    assert_eq!(Hour::MAX + 1, Hour::MIN);
+    assert_eq!(Hour::MIN - 1, Hour::MAX);
+    assert_eq!(Hour::MAX * 2, ranch::Wrapping(ranch::RangedU8::new::<12>()));
+    assert_eq!(Hour::MAX / 5, ranch::Wrapping(ranch::RangedU8::new::<2>()));
+    assert_eq!(Hour::MAX / 13, Hour::MAX);
+    assert_eq!(Hour::MAX % NonZero::new(6u8).unwrap(), Hour::MAX,);
+    assert_eq!(-Hour::MIN, Hour::MAX - 1);
+    assert_eq!(Offset::MAX + 1, Offset::MIN);
+    assert_eq!(-Offset::MIN, Offset::MAX);
+    assert_eq!(Offset::MIN / -1, Offset::MAX);
+    assert_eq!(
+        Offset::MAX / -2,
+        ranch::Wrapping(ranch::RangedI8::new::<-2>())
+    );
+
+    let mut hour = Hour::MIN;
+
+    hour += 23;
+    assert_eq!(hour, Hour::MAX);
+    hour *= 5;
+    assert_eq!(hour, Hour::MAX);
+    hour /= 2;
+    assert_eq!(hour, ranch::Wrapping(ranch::RangedU8::new::<6>()));
+    hour %= NonZero::new(4u8).unwrap();
+    assert_eq!(hour, ranch::Wrapping(ranch::RangedU8::new::<2>()));
+    hour -= 2;
+    assert_eq!(hour, Hour::MAX);
+}
+
+#[test]
+fn wrapping_div_extremes() {
+    type Full = ranch::RangedI8<{ i8::MIN }, { i8::MAX }>;
+    type Small = ranch::RangedI8<-3, 4>;
+
+    assert_eq!(Full::MIN.wrapping_div(-1).number(), Some(Full::MIN));
+    assert_eq!(
+        Small::MIN.wrapping_div(i8::MIN).number(),
+        Some(Small::new::<0>()),
+    );
+    assert_eq!(
+        ranch::RangedI8::<{ i8::MIN }, 0>::MIN
+            .wrapping_div(-1)
+            .number(),
+        Some(ranch::RangedI8::new::<-1>()),
+    );
+}
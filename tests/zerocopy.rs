@@ -0,0 +1,13 @@
+#![cfg(feature = "zerocopy")]
+
+use ranch::{RangedNonZeroU16, RangedU16};
+use zerocopy::IntoBytes;
+
+#[test]
+fn zerocopy() {
+    let samples = [RangedU16::<0, 4095>::MAX, RangedU16::new::<1>()];
+    let lengths = [RangedNonZeroU16::<1, 1500>::new::<0x0102>()];
+
+    assert_eq!(samples.as_bytes(), [4095_u16, 1].as_bytes());
+    assert_eq!(lengths.as_bytes(), 0x0102_u16.to_ne_bytes());
+}